@@ -0,0 +1,272 @@
+use crate::{account_validate, base58, Account, ACCOUNT_ID_LENGTH, ACCOUNT_ID_PREFIX};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// An `Account` holding an `r...` address.
+pub type ClassicAddress = Account;
+
+/**
+ * X-addresses pack the account id and an optional destination tag together. [1]
+ * 2 prefix bytes, 20 byte account id, 1 flag byte, 4 byte little endian tag, 4 reserved bytes.
+ * 1: https://xrpaddress.info/
+ */
+const X_ADDRESS_MAIN_NET_PREFIX: [u8; 2] = [0x05, 0x44];
+const X_ADDRESS_TEST_NET_PREFIX: [u8; 2] = [0x04, 0x93];
+const X_ADDRESS_PAYLOAD_LENGTH: usize = 2 + ACCOUNT_ID_LENGTH + 1 + 8;
+
+struct XAddress {
+    account_id: Vec<u8>,
+    tag: Option<u32>,
+    test_net: bool,
+}
+
+fn x_address_decode(s: &str) -> Result<XAddress, String> {
+    let payload = base58::decode_check(s)?;
+    if payload.len() != X_ADDRESS_PAYLOAD_LENGTH {
+        return Err(format!("{:?} is not an X-address", s));
+    }
+    let test_net = match [payload[0], payload[1]] {
+        X_ADDRESS_MAIN_NET_PREFIX => false,
+        X_ADDRESS_TEST_NET_PREFIX => true,
+        _ => return Err(format!("{:?} has an unknown X-address prefix", s)),
+    };
+    let account_id = payload[2..2 + ACCOUNT_ID_LENGTH].to_vec();
+    let flag = payload[2 + ACCOUNT_ID_LENGTH];
+    let tag_bytes = &payload[3 + ACCOUNT_ID_LENGTH..];
+    if tag_bytes[4..].iter().any(|&byte| byte != 0) {
+        return Err(format!("{:?} uses the reserved 64 bit tag bytes", s));
+    }
+    let tag = u32::from(tag_bytes[0])
+        | u32::from(tag_bytes[1]) << 8
+        | u32::from(tag_bytes[2]) << 16
+        | u32::from(tag_bytes[3]) << 24;
+    let tag = match flag {
+        0 if tag == 0 => None,
+        0 => return Err(format!("{:?} has a tag but its tag flag is unset", s)),
+        1 => Some(tag),
+        _ => return Err(format!("{:?} has an unknown tag flag {}", s, flag)),
+    };
+    Ok(XAddress {
+        account_id,
+        tag,
+        test_net,
+    })
+}
+
+fn x_address_encode(account_id: &[u8], tag: Option<u32>, test_net: bool) -> String {
+    let mut payload = Vec::with_capacity(X_ADDRESS_PAYLOAD_LENGTH);
+    payload.extend_from_slice(if test_net {
+        &X_ADDRESS_TEST_NET_PREFIX
+    } else {
+        &X_ADDRESS_MAIN_NET_PREFIX
+    });
+    payload.extend_from_slice(account_id);
+    payload.push(if tag.is_some() { 1 } else { 0 });
+    let tag = tag.unwrap_or(0);
+    payload.extend_from_slice(&[
+        tag as u8,
+        (tag >> 8) as u8,
+        (tag >> 16) as u8,
+        (tag >> 24) as u8,
+        0,
+        0,
+        0,
+        0,
+    ]);
+    base58::encode_check(&payload)
+}
+
+pub(crate) fn classic_encode(account_id: &[u8]) -> String {
+    let mut payload = Vec::with_capacity(ACCOUNT_ID_LENGTH + 1);
+    payload.push(ACCOUNT_ID_PREFIX);
+    payload.extend_from_slice(account_id);
+    base58::encode_check(&payload)
+}
+
+impl Account {
+    /// Main net (`X...`) and test net (`T...`) X-addresses.
+    pub fn from_x_address(s: &str) -> Result<Account, String> {
+        x_address_decode(s).map(|_| Account(s.into()))
+    }
+
+    pub fn is_x_address(&self) -> bool {
+        x_address_decode(&self.0).is_ok()
+    }
+
+    /// Most rippled methods only take classic addresses, the tag has to travel separately.
+    pub fn to_classic(&self) -> (ClassicAddress, Option<u32>) {
+        match x_address_decode(&self.0) {
+            Ok(x_address) => (Account(classic_encode(&x_address.account_id)), x_address.tag),
+            Err(_) => (self.clone(), None),
+        }
+    }
+
+    pub fn to_x_address(&self, tag: Option<u32>, test_net: bool) -> Result<String, String> {
+        let (classic, existing_tag) = self.to_classic();
+        if existing_tag.is_some() && tag.is_some() && existing_tag != tag {
+            return Err(format!("{:?} already carries tag {:?}", self.0, existing_tag));
+        }
+        account_validate(&classic.0)?;
+        let payload = base58::decode_check(&classic.0)?;
+        Ok(x_address_encode(
+            &payload[1..],
+            tag.or(existing_tag),
+            test_net,
+        ))
+    }
+
+    /// The classic address of a 20 byte AccountID, as rippled writes it in hex.
+    pub fn from_account_id_hex(account_id: &str) -> Result<Account, String> {
+        let bytes = hex::decode(account_id)
+            .map_err(|error| format!("{:?} is not hex: {}", account_id, error))?;
+        if bytes.len() != ACCOUNT_ID_LENGTH {
+            return Err(format!(
+                "{:?} is {} bytes, an AccountID is {}",
+                account_id,
+                bytes.len(),
+                ACCOUNT_ID_LENGTH
+            ));
+        }
+        Ok(Account(classic_encode(&bytes)))
+    }
+
+    /// Uppercase hex of the AccountID, X-addresses give the id of their classic address.
+    pub fn to_account_id_hex(&self) -> String {
+        let (classic, _) = self.to_classic();
+        let payload =
+            base58::decode_check(&classic.0).expect("Accounts are validated on creation");
+        hex::encode_upper(&payload[1..])
+    }
+
+    /// `None` for classic addresses.
+    pub fn is_test_net(&self) -> Option<bool> {
+        x_address_decode(&self.0).ok().map(|x_address| x_address.test_net)
+    }
+
+    /// What accounts compare and hash by, the classic address and the X-address tag.
+    fn canonical(&self) -> (String, Option<u32>) {
+        if self.0.starts_with('r') {
+            return (self.0.clone(), None);
+        }
+        let (classic, tag) = self.to_classic();
+        (classic.0, tag)
+    }
+}
+
+/// An X-address equals its classic address when it has no tag, whichever network it is for.
+impl PartialEq for Account {
+    fn eq(&self, other: &Account) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Account {}
+
+impl Hash for Account {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state)
+    }
+}
+
+impl PartialOrd for Account {
+    fn partial_cmp(&self, other: &Account) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Account {
+    fn cmp(&self, other: &Account) -> Ordering {
+        self.canonical().cmp(&other.canonical())
+    }
+}
+
+#[test]
+fn x_address_main_net_test() {
+    let account = Account::from_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb").unwrap();
+    let (classic, tag) = account.to_classic();
+    assert_eq!(classic.0, "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+    assert_eq!(tag, None);
+    assert_eq!(account.is_test_net(), Some(false));
+
+    let tagged = Account::from_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    assert_eq!(tagged.to_classic().1, Some(1));
+}
+
+#[test]
+fn x_address_test_net_test() {
+    let account = Account::from_x_address("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw").unwrap();
+    let (classic, tag) = account.to_classic();
+    assert_eq!(classic.0, "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf");
+    assert_eq!(tag, Some(1));
+    assert_eq!(account.is_test_net(), Some(true));
+}
+
+#[test]
+fn x_address_tag_round_trip_test() {
+    let classic: Account = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+    for &(tag, test_net) in &[
+        (None, false),
+        (Some(0), false),
+        (Some(14), true),
+        (Some(u32::MAX), false),
+    ] {
+        let x_address = classic.to_x_address(tag, test_net).unwrap();
+        let (round_trip, round_trip_tag) = Account::from_x_address(&x_address).unwrap().to_classic();
+        assert_eq!(round_trip.0, classic.0);
+        assert_eq!(round_trip_tag, tag);
+    }
+    assert_eq!(
+        classic.to_x_address(Some(4_294_967_295), false).unwrap(),
+        "XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi"
+    );
+    assert!(Account::from_x_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").is_err());
+}
+
+#[test]
+fn account_id_hex_round_trip_test() {
+    let genesis =
+        Account::from_account_id_hex("B5F762798A53D543A014CAF8B297CFF8F2F937E8").unwrap();
+    assert_eq!(genesis.0, "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+    assert_eq!(
+        genesis.to_account_id_hex(),
+        "B5F762798A53D543A014CAF8B297CFF8F2F937E8"
+    );
+
+    let leading_zeros = "0000A5F762798A53D543A014CAF8B297CFF8F2F9";
+    let account = Account::from_account_id_hex(leading_zeros).unwrap();
+    assert_eq!(account.0, "rrr8sc6Ga1P6rGhcmYWTL1shqMR58fswW");
+    assert_eq!(account.to_account_id_hex(), leading_zeros);
+    assert_eq!(
+        Account::from_account_id_hex(&"00".repeat(20)).unwrap().0,
+        "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+    );
+    assert_eq!(
+        "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+            .parse::<Account>()
+            .unwrap()
+            .to_account_id_hex(),
+        "00".repeat(20)
+    );
+
+    assert!(Account::from_account_id_hex("B5F762798A53D543A014CAF8B297CFF8F2F937").is_err());
+    assert!(Account::from_account_id_hex("not hex").is_err());
+}
+
+#[test]
+fn account_hash_set_test() {
+    let mut accounts = std::collections::HashSet::new();
+    let first: Account = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf".parse().unwrap();
+    let second: Account = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".parse().unwrap();
+    assert!(accounts.insert(first.clone()));
+    assert!(accounts.insert(second.clone()));
+    assert!(!accounts.insert(first.clone()));
+    assert_eq!(accounts.len(), 2);
+    assert_ne!(first, second);
+
+    let untagged = Account::from_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb").unwrap();
+    let tagged = Account::from_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    assert_eq!(untagged, first);
+    assert!(!accounts.insert(untagged));
+    assert_ne!(tagged, first);
+    assert!(accounts.insert(tagged));
+}
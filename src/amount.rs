@@ -0,0 +1,541 @@
+use crate::{Balance, Currency};
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+
+/**
+ * XRP amounts on the wire are integer drops, 1 XRP = 1,000,000 drops. [1]
+ * 1: https://xrpl.org/currency-formats.html#xrp-amounts
+ */
+pub const XRP_DECIMALS: i64 = 6;
+
+pub fn drops_to_xrp(drops: &BigDecimal) -> BigDecimal {
+    let (digits, scale) = drops.as_bigint_and_exponent();
+    BigDecimal::new(digits, scale + XRP_DECIMALS)
+}
+
+pub fn xrp_to_drops(xrp: &BigDecimal) -> Result<BigDecimal, String> {
+    let (digits, scale) = xrp.as_bigint_and_exponent();
+    let drops = BigDecimal::new(digits, scale - XRP_DECIMALS);
+    if drops.with_scale(0) != drops {
+        return Err(format!(
+            "{} XRP has more than {} decimal places",
+            xrp, XRP_DECIMALS
+        ));
+    }
+    Ok(drops.with_scale(0))
+}
+
+/**
+ * A whole number of drops. rippled writes them as strings, though a few places
+ * use plain numbers, so both are accepted and strings are written back.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Drops(pub u64);
+
+impl Drops {
+    pub fn to_xrp(self) -> BigDecimal {
+        drops_to_xrp(&BigDecimal::from(self.0))
+    }
+}
+
+impl From<u64> for Drops {
+    fn from(drops: u64) -> Self {
+        Drops(drops)
+    }
+}
+
+impl fmt::Display for Drops {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Drops {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Drops {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DropsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DropsVisitor {
+            type Value = Drops;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a whole number of drops as a string or a number")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, drops: u64) -> Result<Drops, E> {
+                Ok(Drops(drops))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, drops: &str) -> Result<Drops, E> {
+                drops
+                    .parse()
+                    .map(Drops)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(drops), &self))
+            }
+        }
+
+        deserializer.deserialize_any(DropsVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+struct IssuedAmount {
+    #[serde(deserialize_with = "crate::currency::deserialize_issued")]
+    currency: Currency,
+    issuer: String,
+    value: BigDecimal,
+}
+
+impl<'de> Deserialize<'de> for Balance {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BalanceVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BalanceVisitor {
+            type Value = Balance;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("drops as a string or number, or an issued amount object")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, drops: u64) -> Result<Balance, E> {
+                Ok(Balance::XRP(Drops(drops)))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, drops: &str) -> Result<Balance, E> {
+                if drops == crate::unavailable_balance::UNAVAILABLE {
+                    return Ok(Balance::Unavailable);
+                }
+                drops
+                    .parse()
+                    .map(|drops| Balance::XRP(Drops(drops)))
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(drops), &self))
+            }
+
+            fn visit_map<M>(self, map: M) -> Result<Balance, M::Error>
+            where
+                M: serde::de::MapAccess<'de>,
+            {
+                let issued = IssuedAmount::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(Balance::Other {
+                    currency: issued.currency,
+                    issuer: issued.issuer,
+                    value: issued.value,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(BalanceVisitor)
+    }
+}
+
+impl Balance {
+    /// The amount in XRP, `None` for issued currencies and unavailable amounts.
+    pub fn as_xrp(&self) -> Option<BigDecimal> {
+        match self {
+            Balance::XRP(drops) => Some(drops.to_xrp()),
+            Balance::Other { .. } | Balance::Unavailable => None,
+        }
+    }
+
+    /// The amount in its own units, XRP rather than drops, `None` when unavailable.
+    pub fn value(&self) -> Option<BigDecimal> {
+        match self {
+            Balance::XRP(drops) => Some(drops.to_xrp()),
+            Balance::Other { value, .. } => Some(value.clone()),
+            Balance::Unavailable => None,
+        }
+    }
+
+    /// XRP from a decimal amount of XRP, refusing negative amounts and fractions of a drop.
+    pub fn from_xrp(xrp: &BigDecimal) -> Result<Balance, String> {
+        if xrp < &BigDecimal::from(0) {
+            return Err(format!("{} XRP is negative", xrp));
+        }
+        let drops = xrp_to_drops(xrp)?;
+        drops
+            .to_string()
+            .parse()
+            .map(|drops| Balance::XRP(Drops(drops)))
+            .map_err(|_| format!("{} XRP does not fit in a u64 of drops", xrp))
+    }
+
+    /// Trust line balances are negative when the holder owes the issuer. XRP never is.
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Balance::Other { value, .. } => value < &BigDecimal::from(0),
+            Balance::XRP(_) | Balance::Unavailable => false,
+        }
+    }
+
+    /// Both XRP, or the same currency from the same issuer. `Unavailable` matches nothing.
+    pub fn same_asset(&self, other: &Balance) -> bool {
+        match (self, other) {
+            (Balance::XRP(_), Balance::XRP(_)) => true,
+            (
+                Balance::Other {
+                    currency, issuer, ..
+                },
+                Balance::Other {
+                    currency: other_currency,
+                    issuer: other_issuer,
+                    ..
+                },
+            ) => currency == other_currency && issuer == other_issuer,
+            _ => false,
+        }
+    }
+
+    /// The sum of two amounts of the same asset, `None` for different assets or too many drops.
+    pub fn checked_add(&self, other: &Balance) -> Option<Balance> {
+        if !self.same_asset(other) {
+            return None;
+        }
+        match (self, other) {
+            (Balance::XRP(drops), Balance::XRP(other_drops)) => {
+                drops.0.checked_add(other_drops.0).map(|sum| Balance::XRP(Drops(sum)))
+            }
+            (
+                Balance::Other {
+                    currency,
+                    issuer,
+                    value,
+                },
+                Balance::Other {
+                    value: other_value,
+                    ..
+                },
+            ) => Some(Balance::Other {
+                currency: currency.clone(),
+                issuer: issuer.clone(),
+                value: value.clone() + other_value.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Orders amounts of the same asset, `None` when `same_asset` is false.
+    pub fn cmp_same_asset(&self, other: &Balance) -> Option<Ordering> {
+        if !self.same_asset(other) {
+            return None;
+        }
+        match (self, other) {
+            (Balance::XRP(drops), Balance::XRP(other_drops)) => Some(drops.cmp(other_drops)),
+            (
+                Balance::Other { value, .. },
+                Balance::Other {
+                    value: other_value,
+                    ..
+                },
+            ) => value.partial_cmp(other_value),
+            _ => None,
+        }
+    }
+}
+
+/// `123.456 XRP` from drops, `100 USD.rIssuer` for issued currencies, for logs and CLIs.
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Balance::XRP(Drops(drops)) => {
+                let whole = drops / 1_000_000;
+                let fraction = drops % 1_000_000;
+                if fraction == 0 {
+                    write!(f, "{} XRP", whole)
+                } else {
+                    let fraction = format!("{:06}", fraction);
+                    write!(f, "{}.{} XRP", whole, fraction.trim_end_matches('0'))
+                }
+            }
+            Balance::Other {
+                currency,
+                issuer,
+                value,
+            } => write!(f, "{} {}.{}", value, currency, issuer),
+            Balance::Unavailable => f.write_str(crate::unavailable_balance::UNAVAILABLE),
+        }
+    }
+}
+
+/**
+ * Issued currency amounts keep 15 significant digits, more would be rounded away. [1]
+ * 1: https://xrpl.org/currency-formats.html#issued-currency-precision
+ */
+pub const ISSUED_SIGNIFICANT_DIGITS: usize = 15;
+
+fn parse_literal(literal: &str) -> Result<BigDecimal, String> {
+    let cleaned: String = literal
+        .chars()
+        .filter(|&c| c != '_' && !c.is_whitespace())
+        .collect();
+    cleaned
+        .parse::<BigDecimal>()
+        .map_err(|_| format!("{:?} is not a decimal number", literal))
+}
+
+/// What `xrp!` expands to, `literal` being the amount in XRP as written.
+#[doc(hidden)]
+pub fn xrp_literal(literal: &str) -> Result<Balance, String> {
+    Balance::from_xrp(&parse_literal(literal)?)
+}
+
+/// What `issued!` expands to, `literal` being the value as written.
+#[doc(hidden)]
+pub fn issued_literal(literal: &str, currency: &str, issuer: &str) -> Result<Balance, String> {
+    let value = parse_literal(literal)?;
+    let (digits, _) = value.as_bigint_and_exponent();
+    let significant = digits.to_string();
+    let significant = significant.trim_start_matches('-').trim_end_matches('0');
+    if significant.len() > ISSUED_SIGNIFICANT_DIGITS {
+        return Err(format!(
+            "{} has more than {} significant digits",
+            value, ISSUED_SIGNIFICANT_DIGITS
+        ));
+    }
+    let currency: Currency = currency.parse()?;
+    if currency == Currency::Xrp {
+        return Err("XRP is not an issued currency, use xrp!".into());
+    }
+    let issuer: crate::Account = issuer.parse()?;
+    Ok(Balance::Other {
+        currency,
+        issuer: issuer.0,
+        value,
+    })
+}
+
+/// `Balance::XRP` of a whole number of drops, `drops!(12)`.
+#[macro_export]
+macro_rules! drops {
+    ($drops:expr) => {
+        $crate::Balance::XRP($crate::Drops($drops))
+    };
+}
+
+/**
+ * `Balance::XRP` from a literal amount of XRP, `xrp!(1.5)` being 1,500,000 drops.
+ * The literal is read as written, not through a float, and an `Err` when negative
+ * or finer than a drop (more than 6 decimal places).
+ */
+#[macro_export]
+macro_rules! xrp {
+    ($xrp:literal) => {
+        $crate::amount::xrp_literal(stringify!($xrp))
+    };
+}
+
+/**
+ * `Balance::Other` from a literal value, currency code and issuer, e.g.
+ * `issued!(100, "USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")`. An `Err` for invalid codes
+ * and issuers, or values with more than `ISSUED_SIGNIFICANT_DIGITS` significant digits.
+ */
+#[macro_export]
+macro_rules! issued {
+    ($value:literal, $currency:expr, $issuer:expr) => {
+        $crate::amount::issued_literal(stringify!($value), $currency, $issuer)
+    };
+}
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn drops_to_xrp_test() {
+    assert_eq!(
+        drops_to_xrp(&BigDecimal::from_str("1").unwrap()),
+        BigDecimal::from_str("0.000001").unwrap()
+    );
+    assert_eq!(
+        drops_to_xrp(&BigDecimal::from_str("100000000000").unwrap()),
+        BigDecimal::from_str("100000").unwrap()
+    );
+}
+
+#[test]
+fn xrp_to_drops_test() {
+    assert_eq!(
+        xrp_to_drops(&BigDecimal::from_str("0.000001").unwrap()),
+        Ok(BigDecimal::from_str("1").unwrap())
+    );
+    assert_eq!(
+        xrp_to_drops(&BigDecimal::from_str("100000").unwrap()),
+        Ok(BigDecimal::from_str("100000000000").unwrap())
+    );
+    assert_eq!(
+        xrp_to_drops(&BigDecimal::from_str("1.500000000").unwrap()),
+        Ok(BigDecimal::from_str("1500000").unwrap())
+    );
+    assert!(xrp_to_drops(&BigDecimal::from_str("0.0000001").unwrap()).is_err());
+}
+
+#[test]
+fn balance_as_xrp_test() {
+    let xrp = Balance::XRP(Drops(1_500_000));
+    assert_eq!(xrp.as_xrp(), Some(BigDecimal::from_str("1.5").unwrap()));
+    let usd = Balance::Other {
+        currency: "USD".parse().unwrap(),
+        issuer: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+        value: BigDecimal::from_str("1.5").unwrap(),
+    };
+    assert_eq!(usd.as_xrp(), None);
+}
+
+#[test]
+fn drops_string_or_number_test() {
+    assert_eq!(serde_json::from_str::<Drops>(r#""12""#).unwrap(), Drops(12));
+    assert_eq!(serde_json::from_str::<Drops>("12").unwrap(), Drops(12));
+    assert!(serde_json::from_str::<Drops>(r#""1.5""#).is_err());
+    assert!(serde_json::from_str::<Drops>("-3").is_err());
+    assert_eq!(serde_json::to_string(&Drops(12)).unwrap(), r#""12""#);
+    assert_eq!(Drops(1_500_000).to_xrp(), BigDecimal::from_str("1.5").unwrap());
+}
+
+#[cfg(test)]
+fn usd(issuer: &str, value: &str) -> Balance {
+    Balance::Other {
+        currency: "USD".parse().unwrap(),
+        issuer: issuer.into(),
+        value: BigDecimal::from_str(value).unwrap(),
+    }
+}
+
+#[test]
+fn balance_checked_add_xrp_test() {
+    let sum = Balance::XRP(Drops(10))
+        .checked_add(&Balance::XRP(Drops(15)))
+        .unwrap();
+    assert_eq!(sum.as_xrp(), Some(BigDecimal::from_str("0.000025").unwrap()));
+    assert!(Balance::XRP(Drops(u64::MAX))
+        .checked_add(&Balance::XRP(Drops(1)))
+        .is_none());
+    assert_eq!(
+        Balance::XRP(Drops(10)).cmp_same_asset(&Balance::XRP(Drops(15))),
+        Some(Ordering::Less)
+    );
+}
+
+#[test]
+fn balance_checked_add_issued_test() {
+    let issuer = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+    match usd(issuer, "1.5").checked_add(&usd(issuer, "2.25")) {
+        Some(Balance::Other { value, .. }) => {
+            assert_eq!(value, BigDecimal::from_str("3.75").unwrap())
+        }
+        other => panic!("expected a USD sum, got {:?}", other),
+    }
+    assert_eq!(
+        usd(issuer, "2").cmp_same_asset(&usd(issuer, "1.5")),
+        Some(Ordering::Greater)
+    );
+    assert!(usd(issuer, "1").checked_add(&Balance::XRP(Drops(1))).is_none());
+}
+
+#[test]
+fn balance_mismatched_issuer_test() {
+    let bitstamp = usd("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "1");
+    let gatehub = usd("rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq", "1");
+    assert!(!bitstamp.same_asset(&gatehub));
+    assert!(bitstamp.checked_add(&gatehub).is_none());
+    assert!(bitstamp.cmp_same_asset(&gatehub).is_none());
+}
+
+#[test]
+fn balance_negative_test() {
+    let owed: Balance = serde_json::from_str(
+        r#"{ "currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "value": "-12.5" }"#,
+    )
+    .unwrap();
+    assert!(owed.is_negative());
+    assert!(owed.as_xrp().is_none());
+
+    let xrp: Balance = serde_json::from_str(r#""1500000""#).unwrap();
+    assert!(!xrp.is_negative());
+    assert_eq!(xrp.as_xrp(), Some(BigDecimal::from_str("1.5").unwrap()));
+
+    assert_eq!(
+        Balance::from_xrp(&BigDecimal::from_str("1.5").unwrap())
+            .unwrap()
+            .as_xrp(),
+        Some(BigDecimal::from_str("1.5").unwrap())
+    );
+    assert!(Balance::from_xrp(&BigDecimal::from_str("-1").unwrap()).is_err());
+    assert!(serde_json::from_str::<Balance>(r#""-1500000""#).is_err());
+}
+
+#[test]
+fn balance_variant_by_shape_test() {
+    match serde_json::from_str::<Balance>("1500000").unwrap() {
+        Balance::XRP(drops) => assert_eq!(drops, Drops(1_500_000)),
+        other => panic!("expected XRP, got {:?}", other),
+    }
+    match serde_json::from_str::<Balance>(r#""1500000""#).unwrap() {
+        Balance::XRP(drops) => assert_eq!(drops, Drops(1_500_000)),
+        other => panic!("expected XRP, got {:?}", other),
+    }
+    let issued = r#"{ "currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "value": "100" }"#;
+    match serde_json::from_str::<Balance>(issued).unwrap() {
+        Balance::Other { currency, value, .. } => {
+            assert_eq!(currency, Currency::Standard("USD".into()));
+            assert_eq!(value, BigDecimal::from(100));
+        }
+        other => panic!("expected an issued amount, got {:?}", other),
+    }
+    match serde_json::from_str::<Balance>(r#""unavailable""#).unwrap() {
+        Balance::Unavailable => {}
+        other => panic!("expected Unavailable, got {:?}", other),
+    }
+    assert!(serde_json::from_str::<Balance>("1.5").is_err());
+    assert!(serde_json::from_str::<Balance>(r#"{ "value": "100" }"#).is_err());
+}
+
+#[test]
+fn balance_display_test() {
+    assert_eq!(Balance::XRP(Drops(123_456_000)).to_string(), "123.456 XRP");
+    assert_eq!(Balance::XRP(Drops(5_000_000)).to_string(), "5 XRP");
+    assert_eq!(Balance::XRP(Drops(1)).to_string(), "0.000001 XRP");
+    let usd = Balance::Other {
+        currency: "USD".parse().unwrap(),
+        issuer: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+        value: BigDecimal::from_str("100").unwrap(),
+    };
+    assert_eq!(usd.to_string(), "100 USD.rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B");
+    assert_eq!(Balance::Unavailable.to_string(), "unavailable");
+}
+
+#[test]
+fn amount_macros_test() {
+    assert_eq!(drops!(12).as_xrp(), Some(BigDecimal::from_str("0.000012").unwrap()));
+    match xrp!(1.5).unwrap() {
+        Balance::XRP(drops) => assert_eq!(drops, Drops(1_500_000)),
+        other => panic!("expected XRP, got {:?}", other),
+    }
+    match xrp!(0.000001).unwrap() {
+        Balance::XRP(drops) => assert_eq!(drops, Drops(1)),
+        other => panic!("expected XRP, got {:?}", other),
+    }
+    assert!(xrp!(0.0000001).is_err());
+    assert!(xrp!(-1).is_err());
+
+    let usd = issued!(100, "USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+    assert_eq!(usd.to_string(), "100 USD.rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B");
+    assert!(issued!(0.1234567890123456, "USD", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").is_err());
+    assert!(issued!(1, "XRP", "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").is_err());
+    assert!(issued!(1, "USD", "not an address").is_err());
+}
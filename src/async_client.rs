@@ -0,0 +1,146 @@
+use crate::capture::{check_id, decode};
+use crate::throttle::Throttle;
+use crate::*;
+use failure::Error;
+use futures::Future as _;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::value::Value;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Instant;
+use tokio::runtime::Runtime;
+use tokio::timer::Delay;
+
+/// The reply of one call, handed over from the runtime to whoever awaits it.
+struct Shared {
+    reply: Option<Result<Value, Error>>,
+    waker: Option<Waker>,
+}
+
+struct Reply(Arc<Mutex<Shared>>);
+
+impl Future for Reply {
+    type Output = Result<Value, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut shared = self.0.lock().unwrap();
+        match shared.reply.take() {
+            Some(reply) => Poll::Ready(reply),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/**
+ * `XRPClient` for async code: the same methods, params and results, over reqwest's async
+ * client. reqwest 0.9 runs on futures 0.1, so the requests are driven by a small runtime
+ * of the client's own and the `async fn`s only wait for their reply, on any executor.
+ * Calls are spaced by `requests_per_sec` like those of `XRPClient::new`.
+ */
+pub struct AsyncXRPClient {
+    url: String,
+    user: Option<String>,
+    pass: Option<String>,
+    http: reqwest::r#async::Client,
+    runtime: Runtime,
+    throttle: Throttle,
+    next_id: AtomicU64,
+}
+
+impl fmt::Debug for AsyncXRPClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncXRPClient")
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+impl AsyncXRPClient {
+    /// At most `requests_per_sec` calls per second, 0 for no limit.
+    pub fn new(
+        url: String,
+        user: Option<String>,
+        pass: Option<String>,
+        requests_per_sec: f64,
+    ) -> Result<AsyncXRPClient, Error> {
+        Ok(AsyncXRPClient {
+            url,
+            user,
+            pass,
+            http: reqwest::r#async::Client::builder().build()?,
+            runtime: Runtime::new()?,
+            throttle: Throttle::new(0, requests_per_sec, 1),
+            next_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Posts `call` on the runtime once the throttle lets it go, and waits for the reply.
+    fn post(&self, call: &Value) -> Reply {
+        let shared = Arc::new(Mutex::new(Shared {
+            reply: None,
+            waker: None,
+        }));
+        let mut post = self.http.post(&self.url).json(call);
+        if let Some(user) = &self.user {
+            post = post.basic_auth(user, self.pass.as_ref());
+        }
+        let delivered = shared.clone();
+        let request = Delay::new(Instant::now() + self.throttle.reserve())
+            .map_err(Error::from)
+            .and_then(move |_| post.send().map_err(Error::from))
+            .and_then(|response| Ok(response.error_for_status()?))
+            .and_then(|mut response| response.json::<Value>().map_err(Error::from))
+            .then(move |reply| {
+                let mut shared = delivered.lock().unwrap();
+                shared.reply = Some(reply);
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+                Ok(())
+            });
+        self.runtime.executor().spawn(request);
+        Reply(shared)
+    }
+
+    /// `XRPClient::request`, awaited.
+    pub async fn request<T, P>(&self, method: &str, params: &P) -> Result<Envelope<T>, Error>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        let params = serde_json::to_value(params)?;
+        let id = json!(self.next_id.fetch_add(1, Ordering::Relaxed));
+        let call = json!({
+            "id": id,
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": [params],
+        });
+        let mut reply = self.post(&call).await?;
+        check_id(&id, &reply)?;
+        let mut envelope = decode(method, reply["result"].take())?;
+        envelope.id = id;
+        Ok(envelope)
+    }
+
+    pub async fn account_info(&self, params: AccountInfoParams<'_>) -> Result<AccountInfo, Error> {
+        self.request("account_info", &params).await?.into_result()
+    }
+
+    pub async fn account_tx(&self, params: AccountTxParams<'_, '_>) -> Result<AccountTx, Error> {
+        self.request("account_tx", &params).await?.into_result()
+    }
+
+    pub async fn ledger(&self, params: LedgerInfoParams) -> Result<LedgerInfo, Error> {
+        self.request("ledger", &params).await?.into_result()
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{AffectedNodeInfo, Balance, Currency, MetaTxInfo};
+use bigdecimal::BigDecimal;
+
+/// How much one account gained (or lost, when negative) of one asset in a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceChange {
+    pub account: String,
+    pub currency: Currency,
+    /// The counterparty of the trust line, `None` for XRP.
+    pub issuer: Option<String>,
+    /// In XRP rather than drops for XRP.
+    pub delta: BigDecimal,
+}
+
+fn limit_account(limit: &Option<Balance>) -> Option<String> {
+    match limit {
+        Some(Balance::Other { issuer, .. }) => Some(issuer.clone()),
+        _ => None,
+    }
+}
+
+/**
+ * A `RippleState` balance is what the high account owes the low one, so the low
+ * account gains the delta and the high account loses it. [1]
+ * 1: https://xrpl.org/ripplestate.html#ripplestate-fields
+ */
+fn node_changes(node: &AffectedNodeInfo) -> Vec<BalanceChange> {
+    let (entry_type, fields, previous) = match node {
+        AffectedNodeInfo::CreatedNode(created) => {
+            (&created.LedgerEntryType, created.NewFields.as_ref(), None)
+        }
+        AffectedNodeInfo::ModifiedNode(modified) => (
+            &modified.LedgerEntryType,
+            modified.FinalFields.as_ref(),
+            modified.PreviousFields.as_ref(),
+        ),
+        AffectedNodeInfo::DeletedNode(deleted) => (
+            &deleted.LedgerEntryType,
+            deleted.FinalFields.as_ref(),
+            deleted.PreviousFields.as_ref(),
+        ),
+    };
+    let fields = match fields {
+        Some(fields) => fields,
+        None => return Vec::new(),
+    };
+    let balance = match fields.Balance.as_ref() {
+        Some(balance) => balance,
+        None => return Vec::new(),
+    };
+    let previous_value = match previous.and_then(|previous| previous.Balance.as_ref()) {
+        Some(previous) => previous.value(),
+        // created nodes start from nothing, the others kept their balance
+        None if is_created(node) => Some(BigDecimal::from(0)),
+        None => None,
+    };
+    let delta = match (balance.value(), previous_value) {
+        (Some(value), Some(previous_value)) => value - previous_value,
+        _ => return Vec::new(),
+    };
+    if delta == BigDecimal::from(0) {
+        return Vec::new();
+    }
+    match (entry_type.as_str(), balance) {
+        ("AccountRoot", Balance::XRP(_)) => fields
+            .Account
+            .iter()
+            .map(|account| BalanceChange {
+                account: account.clone(),
+                currency: Currency::Xrp,
+                issuer: None,
+                delta: delta.clone(),
+            })
+            .collect(),
+        ("RippleState", Balance::Other { currency, .. }) => {
+            let (low, high) = match (
+                limit_account(&fields.LowLimit),
+                limit_account(&fields.HighLimit),
+            ) {
+                (Some(low), Some(high)) => (low, high),
+                _ => return Vec::new(),
+            };
+            vec![
+                BalanceChange {
+                    account: low.clone(),
+                    currency: currency.clone(),
+                    issuer: Some(high.clone()),
+                    delta: delta.clone(),
+                },
+                BalanceChange {
+                    account: high,
+                    currency: currency.clone(),
+                    issuer: Some(low),
+                    delta: -delta,
+                },
+            ]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn is_created(node: &AffectedNodeInfo) -> bool {
+    matches!(node, AffectedNodeInfo::CreatedNode(_))
+}
+
+impl MetaTxInfo {
+    /// What every account gained or lost, from the balances before and after of each node.
+    pub fn balance_changes(&self) -> Vec<BalanceChange> {
+        self.AffectedNodes.iter().flat_map(node_changes).collect()
+    }
+}
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[cfg(test)]
+fn change(account: &str, currency: Currency, issuer: Option<&str>, delta: &str) -> BalanceChange {
+    BalanceChange {
+        account: account.into(),
+        currency,
+        issuer: issuer.map(String::from),
+        delta: BigDecimal::from_str(delta).unwrap(),
+    }
+}
+
+#[test]
+fn balance_changes_xrp_payment_test() {
+    let meta: MetaTxInfo = serde_json::from_str(
+        r#"{
+  "AffectedNodes": [
+    {
+      "ModifiedNode": {
+        "FinalFields": {
+          "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+          "Balance": "89999988",
+          "Flags": 0,
+          "OwnerCount": 0,
+          "Sequence": 5
+        },
+        "LedgerEntryType": "AccountRoot",
+        "LedgerIndex": "2B6AC232AA4C4BE41BF49D2459FA4A0347E1B543A4C92FCEE0821C0201E2E9A8",
+        "PreviousFields": { "Balance": "100000000", "Sequence": 4 }
+      }
+    },
+    {
+      "CreatedNode": {
+        "LedgerEntryType": "AccountRoot",
+        "LedgerIndex": "4F83A2CF7E70F77F79A307E6A472BFC2585B806A70833CCD1C26105BAE0D6E05",
+        "NewFields": {
+          "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+          "Balance": "10000000",
+          "Sequence": 1
+        }
+      }
+    }
+  ],
+  "TransactionIndex": 0,
+  "TransactionResult": "tesSUCCESS"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        meta.balance_changes(),
+        vec![
+            change("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", Currency::Xrp, None, "-10.000012"),
+            change("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", Currency::Xrp, None, "10"),
+        ]
+    );
+}
+
+#[test]
+fn balance_changes_issued_payment_test() {
+    let meta: MetaTxInfo = serde_json::from_str(
+        r#"{
+  "AffectedNodes": [
+    {
+      "ModifiedNode": {
+        "FinalFields": {
+          "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+          "Balance": "99999988",
+          "Flags": 0,
+          "OwnerCount": 0,
+          "Sequence": 8
+        },
+        "LedgerEntryType": "AccountRoot",
+        "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+        "PreviousFields": { "Balance": "100000000", "Sequence": 7 }
+      }
+    },
+    {
+      "ModifiedNode": {
+        "FinalFields": {
+          "Balance": { "currency": "USD", "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji", "value": "-15" },
+          "Flags": 131072,
+          "HighLimit": { "currency": "USD", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "value": "100" },
+          "LowLimit": { "currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "value": "0" }
+        },
+        "LedgerEntryType": "RippleState",
+        "LedgerIndex": "EA4BF03B4700123CDFFB6EB09DC1D6E28D5CEB7F680FB00FC24BC1C3BB2DB959",
+        "PreviousFields": {
+          "Balance": { "currency": "USD", "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji", "value": "-5" }
+        }
+      }
+    }
+  ],
+  "TransactionIndex": 3,
+  "TransactionResult": "tesSUCCESS"
+}"#,
+    )
+    .unwrap();
+    let usd = Currency::Standard("USD".into());
+    assert_eq!(
+        meta.balance_changes(),
+        vec![
+            change("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", Currency::Xrp, None, "-0.000012"),
+            change(
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                usd.clone(),
+                Some("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"),
+                "-10"
+            ),
+            change(
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                usd,
+                Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+                "10"
+            ),
+        ]
+    );
+}
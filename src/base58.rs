@@ -0,0 +1,107 @@
+use sha2::{Digest, Sha256};
+
+/**
+ * XRPL uses its own base58 dictionary, which is why classic addresses start with r. [1]
+ * 1: https://xrpl.org/base58-encodings.html
+ */
+const ALPHABET: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+const CHECKSUM_LENGTH: usize = 4;
+
+fn checksum(payload: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let digest = Sha256::digest(&Sha256::digest(payload));
+    let mut checksum = [0; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&digest[..CHECKSUM_LENGTH]);
+    checksum
+}
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    // little endian base58 digits
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for &byte in data {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let leading_zeros = data.iter().take_while(|&&byte| byte == 0).count();
+    (0..leading_zeros)
+        .map(|_| ALPHABET[0] as char)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| ALPHABET[digit as usize] as char),
+        )
+        .collect()
+}
+
+pub(crate) fn decode(s: &str) -> Result<Vec<u8>, String> {
+    // little endian bytes
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|&letter| letter as char == c)
+            .ok_or_else(|| format!("{:?} contains {:?} which is not base58", s, c))?
+            as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = s
+        .chars()
+        .take_while(|&c| c == ALPHABET[0] as char)
+        .count();
+    bytes.resize(bytes.len() + leading_zeros, 0);
+    bytes.reverse();
+    Ok(bytes)
+}
+
+pub(crate) fn encode_check(payload: &[u8]) -> String {
+    let mut data = payload.to_vec();
+    data.extend_from_slice(&checksum(payload));
+    encode(&data)
+}
+
+/// Returns the payload (type prefix included) once the trailing checksum is verified.
+pub(crate) fn decode_check(s: &str) -> Result<Vec<u8>, String> {
+    let mut data = decode(s)?;
+    if data.len() < CHECKSUM_LENGTH {
+        return Err(format!("{:?} is too short to carry a checksum", s));
+    }
+    let expected = data.split_off(data.len() - CHECKSUM_LENGTH);
+    if expected[..] != checksum(&data)[..] {
+        return Err(format!("{:?} has an invalid checksum", s));
+    }
+    Ok(data)
+}
+
+#[test]
+fn base58_round_trip_test() {
+    let decoded = decode_check("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+    assert_eq!(decoded.len(), 21);
+    assert_eq!(decoded[0], 0);
+    assert_eq!(
+        encode_check(&decoded),
+        "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+    );
+}
+
+#[test]
+fn base58_leading_zeros_test() {
+    let decoded = decode_check("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap();
+    assert_eq!(decoded, vec![0; 21]);
+    assert_eq!(encode_check(&decoded), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+}
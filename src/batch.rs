@@ -0,0 +1,205 @@
+use crate::capture::decode;
+use crate::metrics::error_code;
+use crate::{
+    AccountInfo, AccountInfoParams, AccountTx, AccountTxParams, LedgerInfo, LedgerInfoParams,
+    XRPClient,
+};
+use failure::{format_err, Error};
+use serde_json::json;
+use serde_json::value::Value;
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+enum BatchRequest<'a> {
+    AccountInfo(AccountInfoParams<'a>),
+    AccountTx(AccountTxParams<'a, 'a>),
+    Ledger(LedgerInfoParams),
+}
+
+/// One reply of a `Batch`, the variant matches the request at the same position.
+#[derive(Debug)]
+pub enum BatchResponse {
+    AccountInfo(AccountInfo),
+    AccountTx(AccountTx),
+    Ledger(LedgerInfo),
+}
+
+impl<'a> BatchRequest<'a> {
+    fn to_call(&self, client: &XRPClient, id: usize) -> Result<Value, Error> {
+        let params = match self {
+            BatchRequest::AccountInfo(params) => client.params(params)?,
+            BatchRequest::AccountTx(params) => client.params(params)?,
+            BatchRequest::Ledger(params) => client.params(params)?,
+        };
+        Ok(json!({
+            "id": id,
+            "jsonrpc": "2.0",
+            "method": self.method(),
+            "params": [params],
+        }))
+    }
+
+    fn method(&self) -> &'static str {
+        match self {
+            BatchRequest::AccountInfo(_) => "account_info",
+            BatchRequest::AccountTx(_) => "account_tx",
+            BatchRequest::Ledger(_) => "ledger",
+        }
+    }
+
+    fn parse(&self, result: Value) -> Result<BatchResponse, Error> {
+        let method = self.method();
+        Ok(match self {
+            BatchRequest::AccountInfo(_) => {
+                BatchResponse::AccountInfo(decode(method, result)?.into_result()?)
+            }
+            BatchRequest::AccountTx(_) => {
+                BatchResponse::AccountTx(decode(method, result)?.into_result()?)
+            }
+            BatchRequest::Ledger(_) => BatchResponse::Ledger(decode(method, result)?.into_result()?),
+        })
+    }
+}
+
+/**
+ * Several calls sent as one json rpc batch array, saving a round trip per call. [1]
+ * A batch goes through the throttle of its `XRPClient` like that many single calls.
+ * 1: https://www.jsonrpc.org/specification#batch
+ */
+#[derive(Debug, Clone)]
+pub struct Batch<'a> {
+    client: &'a XRPClient,
+    requests: Vec<BatchRequest<'a>>,
+}
+
+impl XRPClient {
+    pub fn batch(&self) -> Batch<'_> {
+        Batch {
+            client: self,
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Batch<'a> {
+    pub fn account_info(mut self, params: AccountInfoParams<'a>) -> Self {
+        self.requests.push(BatchRequest::AccountInfo(params));
+        self
+    }
+
+    pub fn account_tx(mut self, params: AccountTxParams<'a, 'a>) -> Self {
+        self.requests.push(BatchRequest::AccountTx(params));
+        self
+    }
+
+    pub fn ledger(mut self, params: LedgerInfoParams) -> Self {
+        self.requests.push(BatchRequest::Ledger(params));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /**
+     * The outer `Err` is for the POST itself failing, a call rippled rejected only
+     * turns its own entry into an `Err`. Replies come back in the order of the calls.
+     */
+    pub fn send(self) -> Result<Vec<Result<BatchResponse, Error>>, Error> {
+        if self.requests.is_empty() {
+            return Ok(Vec::new());
+        }
+        let calls = self
+            .requests
+            .iter()
+            .enumerate()
+            .map(|(id, request)| request.to_call(self.client, id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let methods: Vec<_> = self.requests.iter().map(BatchRequest::method).collect();
+        let start = Instant::now();
+        let results = self
+            .client
+            .post(&Value::Array(calls), &methods)
+            .and_then(|reply| Ok(serde_json::from_value(reply)?))
+            .map(|replies| align_replies(&self.requests, replies));
+        if let Some(metrics) = &self.client.metrics {
+            let latency = start.elapsed();
+            match &results {
+                Ok(results) => {
+                    for (method, result) in methods.iter().zip(results) {
+                        metrics.record(method, latency, result.as_ref().err().map(error_code));
+                    }
+                }
+                Err(error) => {
+                    for method in &methods {
+                        metrics.record(method, latency, Some(error_code(error)));
+                    }
+                }
+            }
+        }
+        results
+    }
+}
+
+fn align_replies(requests: &[BatchRequest], replies: Vec<Value>) -> Vec<Result<BatchResponse, Error>> {
+    let mut results: Vec<Option<Value>> = vec![None; requests.len()];
+    for mut reply in replies {
+        let id = reply["id"].as_u64().map(|id| id as usize);
+        if let Some(slot) = id.and_then(|id| results.get_mut(id)) {
+            *slot = Some(reply["result"].take());
+        }
+    }
+    requests
+        .iter()
+        .zip(results)
+        .enumerate()
+        .map(|(id, (request, result))| match result {
+            Some(result) => request.parse(result),
+            None => Err(format_err!("The batch reply is missing call {}", id)),
+        })
+        .collect()
+}
+
+#[test]
+fn batch_align_replies_test() {
+    let ledger = BatchRequest::Ledger(LedgerInfoParams {
+        ledger_hash: None,
+        ledger_index: None,
+        full: None,
+        accounts: None,
+        transactions: None,
+        expand: None,
+        owner_funds: None,
+        binary: None,
+        queue: None,
+    });
+    let requests = vec![ledger.clone(), ledger.clone(), ledger];
+    let replies = vec![
+        json!({
+            "id": 1,
+            "result": {
+                "error": "lgrNotFound",
+                "error_code": 21,
+                "error_message": "ledgerNotFound",
+                "status": "error"
+            }
+        }),
+        json!({ "id": 7, "result": {} }),
+    ];
+    let results = align_replies(&requests, replies);
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[1]
+            .as_ref()
+            .unwrap_err()
+            .downcast_ref::<crate::XrpError>()
+            .map(crate::XrpError::code),
+        Some("lgrNotFound")
+    );
+    assert!(results[0].is_err());
+    assert!(results[2].is_err());
+}
@@ -0,0 +1,480 @@
+use crate::address::classic_encode;
+use crate::{Currency, TransactionInfo, TransactionType, ACCOUNT_ID_LENGTH};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha512};
+
+/// What transaction ids are hashed with, `TXN\0`.
+const TRANSACTION_ID_PREFIX: [u8; 4] = [0x54, 0x58, 0x4E, 0x00];
+const AMOUNT_LENGTH: usize = 8;
+const CURRENCY_LENGTH: usize = 20;
+const ISSUED_EXPONENT_BIAS: i32 = 97;
+
+const TYPE_UINT16: u8 = 1;
+const TYPE_UINT32: u8 = 2;
+const TYPE_UINT64: u8 = 3;
+const TYPE_HASH128: u8 = 4;
+const TYPE_HASH256: u8 = 5;
+const TYPE_AMOUNT: u8 = 6;
+const TYPE_BLOB: u8 = 7;
+const TYPE_ACCOUNT_ID: u8 = 8;
+const TYPE_OBJECT: u8 = 14;
+const TYPE_ARRAY: u8 = 15;
+const TYPE_UINT8: u8 = 16;
+const TYPE_HASH160: u8 = 17;
+const TYPE_PATH_SET: u8 = 18;
+const TYPE_VECTOR256: u8 = 19;
+
+const OBJECT_END: (u8, u8) = (TYPE_OBJECT, 1);
+const ARRAY_END: (u8, u8) = (TYPE_ARRAY, 1);
+const TRANSACTION_TYPE: (u8, u8) = (TYPE_UINT16, 2);
+
+const PATH_END: u8 = 0x00;
+const PATH_BOUNDARY: u8 = 0xFF;
+const PATH_STEP_ACCOUNT: u8 = 0x01;
+const PATH_STEP_CURRENCY: u8 = 0x10;
+const PATH_STEP_ISSUER: u8 = 0x20;
+
+/**
+ * `(type code, field code, name)` of the fields Payment, OfferCreate and TrustSet
+ * use, plus the common and memo fields. [1]
+ * 1: https://xrpl.org/serialization.html#field-ids
+ */
+const FIELDS: &[(u8, u8, &str)] = &[
+    (TYPE_UINT16, 2, "TransactionType"),
+    (TYPE_UINT32, 1, "NetworkID"),
+    (TYPE_UINT32, 2, "Flags"),
+    (TYPE_UINT32, 3, "SourceTag"),
+    (TYPE_UINT32, 4, "Sequence"),
+    (TYPE_UINT32, 10, "Expiration"),
+    (TYPE_UINT32, 14, "DestinationTag"),
+    (TYPE_UINT32, 20, "QualityIn"),
+    (TYPE_UINT32, 21, "QualityOut"),
+    (TYPE_UINT32, 25, "OfferSequence"),
+    (TYPE_UINT32, 27, "LastLedgerSequence"),
+    (TYPE_UINT32, 41, "TicketSequence"),
+    (TYPE_HASH256, 9, "AccountTxnID"),
+    (TYPE_HASH256, 17, "InvoiceID"),
+    (TYPE_AMOUNT, 1, "Amount"),
+    (TYPE_AMOUNT, 3, "LimitAmount"),
+    (TYPE_AMOUNT, 4, "TakerPays"),
+    (TYPE_AMOUNT, 5, "TakerGets"),
+    (TYPE_AMOUNT, 8, "Fee"),
+    (TYPE_AMOUNT, 9, "SendMax"),
+    (TYPE_AMOUNT, 10, "DeliverMin"),
+    (TYPE_BLOB, 3, "SigningPubKey"),
+    (TYPE_BLOB, 4, "TxnSignature"),
+    (TYPE_BLOB, 12, "MemoType"),
+    (TYPE_BLOB, 13, "MemoData"),
+    (TYPE_BLOB, 14, "MemoFormat"),
+    (TYPE_ACCOUNT_ID, 1, "Account"),
+    (TYPE_ACCOUNT_ID, 3, "Destination"),
+    (TYPE_OBJECT, 10, "Memo"),
+    (TYPE_OBJECT, 16, "Signer"),
+    (TYPE_ARRAY, 3, "Signers"),
+    (TYPE_ARRAY, 9, "Memos"),
+    (TYPE_UINT8, 16, "TickSize"),
+    (TYPE_PATH_SET, 1, "Paths"),
+];
+
+fn field_name(type_code: u8, field_code: u8) -> Option<&'static str> {
+    FIELDS
+        .iter()
+        .find(|(field_type, code, _)| *field_type == type_code && *code == field_code)
+        .map(|(_, _, name)| *name)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self.position + length;
+        if end > self.bytes.len() {
+            return Err(format!(
+                "Needed {} bytes at offset {}, the blob is only {} long",
+                length,
+                self.position,
+                self.bytes.len()
+            ));
+        }
+        let taken = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn uint(&mut self, length: usize) -> Result<u64, String> {
+        let bytes = self.take(length)?;
+        Ok(bytes
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | u64::from(byte)))
+    }
+
+    /// Small type and field codes share one byte, larger ones get a byte of their own.
+    fn field_id(&mut self) -> Result<(u8, u8), String> {
+        let first = self.byte()?;
+        let type_code = match first >> 4 {
+            0 => self.byte()?,
+            code => code,
+        };
+        let field_code = match first & 0x0F {
+            0 => self.byte()?,
+            code => code,
+        };
+        Ok((type_code, field_code))
+    }
+
+    /**
+     * Blobs and account ids are prefixed with their length in one to three bytes. [1]
+     * 1: https://xrpl.org/serialization.html#length-prefixing
+     */
+    fn length_prefix(&mut self) -> Result<usize, String> {
+        let first = usize::from(self.byte()?);
+        Ok(match first {
+            0..=192 => first,
+            193..=240 => 193 + (first - 193) * 256 + usize::from(self.byte()?),
+            241..=254 => {
+                let rest = self.uint(2)? as usize;
+                12_481 + (first - 241) * 65_536 + rest
+            }
+            _ => return Err("255 is not a valid length prefix".into()),
+        })
+    }
+
+    fn hex(&mut self, length: usize) -> Result<Value, String> {
+        self.take(length).map(|bytes| hex::encode_upper(bytes).into())
+    }
+
+    fn account_id(&mut self) -> Result<String, String> {
+        self.take(ACCOUNT_ID_LENGTH).map(classic_encode)
+    }
+
+    fn currency(&mut self) -> Result<Currency, String> {
+        hex::encode_upper(self.take(CURRENCY_LENGTH)?).parse()
+    }
+
+    /**
+     * XRP is 8 bytes of drops, issued amounts add the currency and issuer and
+     * keep the value as a 54 bit mantissa and an exponent. [1]
+     * 1: https://xrpl.org/serialization.html#amount-fields
+     */
+    fn amount(&mut self) -> Result<Value, String> {
+        let bits = self.uint(AMOUNT_LENGTH)?;
+        let positive = bits & (1 << 62) != 0;
+        if bits & (1 << 63) == 0 {
+            let drops = bits & ((1 << 62) - 1);
+            let sign = if positive || drops == 0 { "" } else { "-" };
+            return Ok(format!("{}{}", sign, drops).into());
+        }
+        let exponent = ((bits >> 54) & 0xFF) as i32 - ISSUED_EXPONENT_BIAS;
+        let mantissa = bits & ((1 << 54) - 1);
+        let mut amount = Map::new();
+        amount.insert("currency".into(), self.currency()?.to_string().into());
+        amount.insert("issuer".into(), self.account_id()?.into());
+        amount.insert(
+            "value".into(),
+            issued_value(!positive, mantissa, exponent).into(),
+        );
+        Ok(Value::Object(amount))
+    }
+
+    fn path_set(&mut self) -> Result<Value, String> {
+        let mut paths = Vec::new();
+        let mut path = Vec::new();
+        loop {
+            let step_type = self.byte()?;
+            if step_type == PATH_END || step_type == PATH_BOUNDARY {
+                paths.push(Value::Array(std::mem::take(&mut path)));
+                if step_type == PATH_END {
+                    return Ok(Value::Array(paths));
+                }
+                continue;
+            }
+            let mut step = Map::new();
+            if step_type & PATH_STEP_ACCOUNT != 0 {
+                step.insert("account".into(), self.account_id()?.into());
+            }
+            if step_type & PATH_STEP_CURRENCY != 0 {
+                step.insert("currency".into(), self.currency()?.to_string().into());
+            }
+            if step_type & PATH_STEP_ISSUER != 0 {
+                step.insert("issuer".into(), self.account_id()?.into());
+            }
+            step.insert("type".into(), step_type.into());
+            step.insert("type_hex".into(), format!("{:016X}", step_type).into());
+            path.push(Value::Object(step));
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        let mut elements = Vec::new();
+        loop {
+            let (type_code, field_code) = self.field_id()?;
+            if (type_code, field_code) == ARRAY_END {
+                return Ok(Value::Array(elements));
+            }
+            if type_code != TYPE_OBJECT {
+                return Err(format!("Arrays hold objects, not type {}", type_code));
+            }
+            let name = field_name(type_code, field_code)
+                .ok_or_else(|| format!("Unknown array element field {}", field_code))?;
+            let mut element = Map::new();
+            element.insert(name.into(), self.object(Some(OBJECT_END))?);
+            elements.push(Value::Object(element));
+        }
+    }
+
+    /// The fields up to `end`, or up to the end of the blob for the transaction itself.
+    fn object(&mut self, end: Option<(u8, u8)>) -> Result<Value, String> {
+        let mut object = Map::new();
+        loop {
+            if end.is_none() && self.is_empty() {
+                return Ok(Value::Object(object));
+            }
+            let id = self.field_id()?;
+            if Some(id) == end {
+                return Ok(Value::Object(object));
+            }
+            let (type_code, field_code) = id;
+            let value = match type_code {
+                TYPE_UINT8 => self.uint(1)?.into(),
+                TYPE_UINT16 if id == TRANSACTION_TYPE => {
+                    let code = self.uint(2)? as u16;
+                    TransactionType::from_code(code)
+                        .ok_or_else(|| format!("Unknown transaction type code {}", code))?
+                        .as_str()
+                        .into()
+                }
+                TYPE_UINT16 => self.uint(2)?.into(),
+                TYPE_UINT32 => self.uint(4)?.into(),
+                TYPE_UINT64 => self.hex(8)?,
+                TYPE_HASH128 => self.hex(16)?,
+                TYPE_HASH160 => self.hex(20)?,
+                TYPE_HASH256 => self.hex(32)?,
+                TYPE_AMOUNT => self.amount()?,
+                TYPE_BLOB => {
+                    let length = self.length_prefix()?;
+                    self.hex(length)?
+                }
+                TYPE_ACCOUNT_ID => {
+                    let length = self.length_prefix()?;
+                    if length != ACCOUNT_ID_LENGTH {
+                        return Err(format!("An AccountID of {} bytes", length));
+                    }
+                    self.account_id()?.into()
+                }
+                TYPE_OBJECT => self.object(Some(OBJECT_END))?,
+                TYPE_ARRAY => self.array()?,
+                TYPE_PATH_SET => self.path_set()?,
+                TYPE_VECTOR256 => {
+                    let length = self.length_prefix()?;
+                    let hashes = self.take(length)?;
+                    hashes
+                        .chunks(32)
+                        .map(|hash| Value::from(hex::encode_upper(hash)))
+                        .collect::<Vec<_>>()
+                        .into()
+                }
+                _ => return Err(format!("Unknown type code {}", type_code)),
+            };
+            // fields outside `FIELDS` are read past, `TransactionInfo` has nowhere to keep them
+            if let Some(name) = field_name(type_code, field_code) {
+                object.insert(name.into(), value);
+            }
+        }
+    }
+}
+
+/// `mantissa * 10^exponent` written out the way rippled does, without an exponent.
+fn issued_value(negative: bool, mantissa: u64, exponent: i32) -> String {
+    if mantissa == 0 {
+        return "0".into();
+    }
+    let mut digits = mantissa.to_string();
+    let mut exponent = exponent;
+    while digits.ends_with('0') {
+        digits.pop();
+        exponent += 1;
+    }
+    let unsigned = if exponent >= 0 {
+        digits + &"0".repeat(exponent as usize)
+    } else {
+        let point = digits.len() as i32 + exponent;
+        if point > 0 {
+            let (whole, fraction) = digits.split_at(point as usize);
+            format!("{}.{}", whole, fraction)
+        } else {
+            format!("0.{}{}", "0".repeat(-point as usize), digits)
+        }
+    };
+    if negative {
+        format!("-{}", unsigned)
+    } else {
+        unsigned
+    }
+}
+
+/**
+ * Decodes a signed or unsigned `tx_blob` without asking a server, `hash` being the id
+ * the blob would have once signed. Payment, OfferCreate and TrustSet are fully supported;
+ * fields this crate has no name for are skipped. [1]
+ * 1: https://xrpl.org/serialization.html
+ */
+pub fn decode_tx_blob(blob: &str) -> Result<TransactionInfo, String> {
+    let bytes = hex::decode(blob).map_err(|error| format!("The blob is not hex: {}", error))?;
+    let mut reader = Reader {
+        bytes: &bytes,
+        position: 0,
+    };
+    let mut transaction = match reader.object(None)? {
+        Value::Object(transaction) => transaction,
+        _ => unreachable!("object always gives an object"),
+    };
+    let mut hashed = TRANSACTION_ID_PREFIX.to_vec();
+    hashed.extend_from_slice(&bytes);
+    let hash = hex::encode_upper(&Sha512::digest(&hashed)[..32]);
+    transaction.insert("hash".into(), hash.into());
+    serde_json::from_value(Value::Object(transaction)).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+const SIGNING_PUB_KEY: &str = "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020";
+
+#[cfg(test)]
+const TXN_SIGNATURE: &str = "3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE";
+
+#[test]
+fn decode_offer_create_test() {
+    // the OfferCreate example of https://xrpl.org/serialization.html
+    let offer = decode_tx_blob(
+        "120007220008000024001ABED82A2380BF2C2019001ABED764D55920AC9391400000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D165400000037E11D60068400000000000000A732103EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3744630440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C8114DD76483FACDEE26E60D8A586BB58D09F27045C46",
+    )
+    .unwrap();
+    assert_eq!(offer.TransactionType, TransactionType::OfferCreate);
+    assert_eq!(offer.Account.unwrap(), "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys");
+    assert_eq!(offer.Expiration, Some(595_640_108));
+    assert_eq!(offer.Fee, Some(crate::Drops(10)));
+    assert_eq!(offer.Flags, Some(524_288));
+    assert_eq!(offer.OfferSequence, Some(1_752_791));
+    assert_eq!(offer.Sequence, Some(1_752_792));
+    assert_eq!(
+        serde_json::to_value(&offer.TakerGets).unwrap(),
+        serde_json::json!("15000000000")
+    );
+    assert_eq!(
+        serde_json::to_value(&offer.TakerPays).unwrap(),
+        serde_json::json!({
+            "currency": "USD",
+            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "value": "7072.8"
+        })
+    );
+    assert_eq!(
+        offer.SigningPubKey.unwrap(),
+        "03EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3"
+    );
+    assert_eq!(
+        offer.hash,
+        "73734B611DDA23D3F5F62E20A173B78AB8406AC5015094DA53F53D39B9EDB06C"
+    );
+}
+
+#[test]
+fn decode_payment_test() {
+    let payment = decode_tx_blob(
+        "120000228002000024000000022E0000000D201B0363B1E061D448E1BC9BF0400000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D168400000000000000C6940000000000F424073210330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD02074473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE8114B5F762798A53D543A014CAF8B297CFF8F2F937E883144B4E9C06F24296074F7BC48F92A97916C6DC5EA9F9EA7C07696E766F6963657D0472656E74E1F101123000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D1FF01DD76483FACDEE26E60D8A586BB58D09F27045C4600",
+    )
+    .unwrap();
+    assert_eq!(payment.TransactionType, TransactionType::Payment);
+    assert_eq!(payment.Account.unwrap(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+    assert_eq!(
+        payment.Destination.unwrap(),
+        "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+    );
+    assert_eq!(payment.DestinationTag, Some(13));
+    assert_eq!(payment.LastLedgerSequence, Some(56_865_248));
+    assert_eq!(payment.Flags, Some(2_147_614_720));
+    assert_eq!(
+        serde_json::to_value(&payment.Amount).unwrap(),
+        serde_json::json!({
+            "currency": "USD",
+            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "value": "0.25"
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&payment.SendMax).unwrap(),
+        serde_json::json!("1000000")
+    );
+    let paths = payment.Paths.unwrap();
+    assert_eq!(paths.len(), 2);
+    assert_eq!(paths[0][0].currency, Some(Currency::Standard("USD".into())));
+    assert_eq!(
+        paths[0][0].issuer.as_ref().unwrap(),
+        "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"
+    );
+    assert_eq!(paths[0][0].type_hex, "0000000000000030");
+    assert_eq!(
+        paths[1][0].account.as_ref().unwrap(),
+        "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys"
+    );
+    let memos = payment.Memos.unwrap();
+    assert_eq!(memos[0].Memo.type_utf8().unwrap(), "invoice");
+    assert_eq!(memos[0].Memo.data_utf8().unwrap(), "rent");
+    assert_eq!(payment.SigningPubKey.unwrap(), SIGNING_PUB_KEY);
+    assert_eq!(payment.TxnSignature.unwrap(), TXN_SIGNATURE);
+    assert_eq!(
+        payment.hash,
+        "7597F0F9073B2998F80F74CB20F155D251521D3259410DB10C853484AC1B3646"
+    );
+}
+
+#[test]
+fn decode_trust_set_test() {
+    let trust_set = decode_tx_blob(
+        "1200142200040000240000000C2014389FD98063D5038D7EA4C6800000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D168400000000000000C73210330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD02074473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA9",
+    )
+    .unwrap();
+    assert_eq!(trust_set.TransactionType, TransactionType::TrustSet);
+    assert_eq!(trust_set.Account.unwrap(), "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+    assert_eq!(trust_set.Fee, Some(crate::Drops(12)));
+    assert_eq!(trust_set.Flags, Some(262_144));
+    assert_eq!(trust_set.QualityIn, Some(950_000_000));
+    assert_eq!(trust_set.Sequence, Some(12));
+    assert_eq!(
+        serde_json::to_value(&trust_set.LimitAmount).unwrap(),
+        serde_json::json!({
+            "currency": "USD",
+            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "value": "100"
+        })
+    );
+    assert_eq!(
+        trust_set.hash,
+        "CCCE0E75EAD20F4A993C21A3A5E6C3B3AE03168E8F47069CFFA92334A2698C21"
+    );
+}
+
+#[test]
+fn decode_truncated_blob_test() {
+    assert!(decode_tx_blob("1200072200080000240001").is_err());
+    assert!(decode_tx_blob("not hex").is_err());
+}
+
+#[test]
+fn issued_value_test() {
+    assert_eq!(issued_value(false, 7_072_800_000_000_000, -12), "7072.8");
+    assert_eq!(issued_value(false, 1_000_000_000_000_000, -15), "1");
+    assert_eq!(issued_value(true, 2_500_000_000_000_000, -31), "-0.00000000000000025");
+    assert_eq!(issued_value(false, 1_000_000_000_000_000, 3), "1000000000000000000");
+    assert_eq!(issued_value(false, 0, 0), "0");
+}
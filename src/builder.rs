@@ -0,0 +1,433 @@
+use crate::capture::IdStrategy;
+use crate::metrics::Metrics;
+use crate::pool::Pool;
+use crate::retry::RetryPolicy;
+use crate::throttle::Throttle;
+use crate::timeout::TimeoutPolicy;
+use crate::{FailoverClient, FailoverStrategy, XRPClient};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
+use reqwest::{Certificate, Identity};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A PKCS #12 archive and its password, left out of `Debug` like other secrets.
+#[derive(Clone)]
+struct ClientIdentity {
+    pkcs12_der: Vec<u8>,
+    password: String,
+}
+
+impl fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ClientIdentity")
+    }
+}
+
+/// Where calls are recorded, left out of `Debug` as the recorder need not implement it.
+#[derive(Clone)]
+struct MetricsRecorder(Arc<dyn Metrics + Send + Sync>);
+
+impl fmt::Debug for MetricsRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MetricsRecorder")
+    }
+}
+
+/**
+ * Every `XRPClient` call goes through the throttle of its client, so building one
+ * `Arc<XRPClient>` and cloning it gives all callers a single budget.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct XRPClientBuilder {
+    url: Option<String>,
+    fallback_urls: Vec<String>,
+    user: Option<String>,
+    pass: Option<String>,
+    max_in_flight: usize,
+    requests_per_sec: f64,
+    burst: usize,
+    gzip: Option<bool>,
+    headers: Vec<(String, String)>,
+    bearer_token: Option<String>,
+    api_version: Option<u32>,
+    root_certificates_pem: Vec<Vec<u8>>,
+    client_identity: Option<ClientIdentity>,
+    accept_invalid_certs: bool,
+    max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    ids: Option<IdStrategy>,
+    capture_raw: bool,
+    retry: Option<RetryPolicy>,
+    timeouts: Option<TimeoutPolicy>,
+    metrics: Option<MetricsRecorder>,
+    #[cfg(feature = "cache")]
+    cache_capacity: Option<usize>,
+}
+
+impl XRPClient {
+    pub fn builder() -> XRPClientBuilder {
+        XRPClientBuilder::default()
+    }
+}
+
+impl XRPClientBuilder {
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// The first url is the primary one, `build` only uses it and `build_failover` all of them.
+    pub fn urls(mut self, urls: &[&str]) -> Self {
+        self.url = urls.first().map(|&url| url.into());
+        self.fallback_urls = urls.iter().skip(1).map(|&url| url.into()).collect();
+        self
+    }
+
+    pub fn auth(mut self, user: &str, pass: Option<&str>) -> Self {
+        self.user = Some(user.into());
+        self.pass = pass.map(|pass| pass.into());
+        self
+    }
+
+    /**
+     * A token bucket: `requests_per_sec` on average, and after a quiet spell up to
+     * `burst` calls right away before the rate applies again.
+     */
+    pub fn rate_limit(mut self, requests_per_sec: f64, burst: usize) -> Self {
+        self.requests_per_sec = requests_per_sec;
+        self.burst = burst;
+        self
+    }
+
+    /// At most `max` calls waiting for a reply at once, across all threads sharing the client.
+    pub fn max_in_flight(mut self, max: usize) -> Self {
+        self.max_in_flight = max;
+        self
+    }
+
+    /**
+     * Whether to ask for gzip compressed replies, which `ledger` with transactions and
+     * `ledger_data` pages shrink a lot under. On by default; turn it off behind a proxy
+     * that already decompresses.
+     */
+    pub fn gzip(mut self, enabled: bool) -> Self {
+        self.gzip = Some(enabled);
+        self
+    }
+
+    /**
+     * Sent with every request, for proxies in front of hosted endpoints. A later value
+     * for the same name replaces the earlier one, and the json rpc `Content-Type` is
+     * always kept. An invalid name or value makes `build` fail.
+     */
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// An `Authorization: Bearer <token>` header, sent like the ones from `header`.
+    pub fn bearer_token(mut self, token: &str) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /**
+     * Adds `api_version` to the params of every request. Unset, nothing is sent and
+     * rippled answers in version 1, which is what the response structs are written for. [1]
+     * 1: https://xrpl.org/request-formatting.html#api-versioning
+     */
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /**
+     * Trusts a PEM encoded CA certificate on top of the system ones, for private clusters
+     * whose rippled servers use a self-signed or internal chain. `build` fails on a
+     * certificate that does not parse.
+     */
+    pub fn root_certificate_pem(mut self, pem: &[u8]) -> Self {
+        self.root_certificates_pem.push(pem.into());
+        self
+    }
+
+    /// A client certificate for servers that require mutual TLS, as a PKCS #12 archive.
+    /// Like `root_certificate_pem`, an archive that does not open makes `build` fail.
+    pub fn client_identity_pkcs12(mut self, der: &[u8], password: &str) -> Self {
+        self.client_identity = Some(ClientIdentity {
+            pkcs12_der: der.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /**
+     * Dangerous: accepts any server certificate, expired, self-signed or for another host,
+     * so anyone in between can read and change the traffic, secrets of `sign` included.
+     * Prefer `root_certificate_pem`; this is for throwaway test networks only.
+     */
+    pub fn accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Idle connections to one node kept open for later calls, 0 closes each one after use.
+    pub fn max_idle_per_host(mut self, max: usize) -> Self {
+        self.max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Once no call went out for this long, the kept connections are closed.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// How the json rpc `id` of each request is picked, 1, 2, 3 and so on by default.
+    pub fn id_strategy(mut self, ids: IdStrategy) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /**
+     * Keeps the whole `result` object in the `raw` of every `Envelope` from `request`,
+     * for seeing what rippled sent that the response structs do not model.
+     */
+    pub fn capture_raw(mut self, enabled: bool) -> Self {
+        self.capture_raw = enabled;
+        self
+    }
+
+    /**
+     * Sends a call again when it fails for a transient reason, by default rippled being busy
+     * or a 503. Only the methods in `IDEMPOTENT_METHODS` are retried, `submit` never is.
+     */
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /**
+     * How long a call may take before it fails with `TimedOut`, from sending the request to
+     * reading the whole reply. Unset, reqwest's own 30 seconds apply to every method.
+     */
+    pub fn timeout(mut self, timeouts: TimeoutPolicy) -> Self {
+        self.timeouts = Some(timeouts);
+        self
+    }
+
+    /**
+     * Records every call of the client with `metrics`, each call of a batch too, e.g. into
+     * an `AtomicMetrics` shared with an exporter.
+     */
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics + Send + Sync>) -> Self {
+        self.metrics = Some(MetricsRecorder(metrics));
+        self
+    }
+
+    fn default_headers(&self) -> Result<HeaderMap, String> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| format!("{:?} is not a valid header name", name))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| format!("{:?} is not a valid header value", value))?;
+            headers.insert(name, value);
+        }
+        if let Some(token) = &self.bearer_token {
+            let value = HeaderValue::from_str(&format!("Bearer {}", token))
+                .map_err(|_| "The bearer token is not a valid header value".to_owned())?;
+            headers.insert(AUTHORIZATION, value);
+        }
+        Ok(headers)
+    }
+
+    pub(crate) fn http_client(&self, timeout: Option<Duration>) -> Result<reqwest::Client, String> {
+        let mut builder = reqwest::Client::builder()
+            .gzip(self.gzip.unwrap_or(true))
+            .default_headers(self.default_headers()?)
+            .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(max) = self.max_idle_per_host {
+            builder = builder.max_idle_per_host(max);
+        }
+        for pem in &self.root_certificates_pem {
+            let certificate = Certificate::from_pem(pem)
+                .map_err(|error| format!("Invalid root certificate: {}", error))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        if let Some(identity) = &self.client_identity {
+            let identity = Identity::from_pkcs12_der(&identity.pkcs12_der, &identity.password)
+                .map_err(|error| format!("Invalid client certificate: {}", error))?;
+            builder = builder.identity(identity);
+        }
+        builder.build().map_err(|error| error.to_string())
+    }
+
+    fn client(&self, url: &str) -> Result<Arc<XRPClient>, String> {
+        let builder = self.clone();
+        let connect = move |timeout| builder.http_client(timeout);
+        let mut client = XRPClient::from_parts(
+            url.into(),
+            self.user.clone(),
+            self.pass.clone(),
+            Pool::new(Box::new(connect), self.pool_idle_timeout)?,
+            Throttle::new(self.max_in_flight, self.requests_per_sec, self.burst),
+        );
+        client.ids = self.ids.unwrap_or(IdStrategy::Monotonic);
+        client.capture_raw = self.capture_raw;
+        client.api_version = self.api_version;
+        client.retry = self.retry.clone();
+        client.timeouts = self.timeouts.clone();
+        client.metrics = self.metrics.as_ref().map(|metrics| metrics.0.clone());
+        Ok(Arc::new(client))
+    }
+
+    fn validate(&self) -> Result<&str, String> {
+        let url = self.url.as_ref().ok_or_else(|| "XRPClient needs a url".to_owned())?;
+        if self.requests_per_sec < 0.0 || !self.requests_per_sec.is_finite() {
+            return Err(format!(
+                "{} is not a valid number of requests per second",
+                self.requests_per_sec
+            ));
+        }
+        Ok(url)
+    }
+
+    /// `cache` only applies to `build_cached`, the other builds refuse it rather than ignore it.
+    fn check_uncached(&self) -> Result<(), String> {
+        #[cfg(feature = "cache")]
+        {
+            if self.cache_capacity.is_some() {
+                return Err("cache(capacity) needs build_cached, build does not cache".into());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn build(self) -> Result<Arc<XRPClient>, String> {
+        let url = self.validate()?;
+        self.check_uncached()?;
+        self.client(url)
+    }
+
+    /**
+     * How many validated ledgers and transactions `build_cached` keeps, each. `build` and
+     * `build_failover` fail once it is set, their clients have no cache.
+     */
+    #[cfg(feature = "cache")]
+    pub fn cache(mut self, capacity: usize) -> Self {
+        self.cache_capacity = Some(capacity);
+        self
+    }
+
+    #[cfg(feature = "cache")]
+    pub fn build_cached(self) -> Result<crate::cache::CachedClient, String> {
+        let url = self.validate()?;
+        let capacity = self
+            .cache_capacity
+            .unwrap_or(crate::cache::DEFAULT_CAPACITY);
+        Ok(crate::cache::CachedClient::new(self.client(url)?, capacity))
+    }
+
+    /// One client per url, each with its own copy of the rate limit.
+    pub fn build_failover(self, strategy: FailoverStrategy) -> Result<FailoverClient, String> {
+        let url = self.validate()?;
+        self.check_uncached()?;
+        let clients = std::iter::once(url)
+            .chain(self.fallback_urls.iter().map(String::as_str))
+            .map(|url| self.client(url))
+            .collect::<Result<_, _>>()?;
+        FailoverClient::new(clients, strategy)
+    }
+}
+
+#[test]
+fn builder_requires_url_test() {
+    assert!(XRPClient::builder().rate_limit(10.0, 1).build().is_err());
+    assert!(XRPClient::builder()
+        .url("http://localhost:51234")
+        .rate_limit(-1.0, 1)
+        .build()
+        .is_err());
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn builder_cache_needs_build_cached_test() {
+    let builder = XRPClient::builder().url("http://localhost:51234").cache(16);
+    assert!(builder.clone().build().is_err());
+    assert!(builder
+        .clone()
+        .build_failover(FailoverStrategy::RoundRobin)
+        .is_err());
+    assert!(builder.build_cached().is_ok());
+}
+
+#[test]
+fn builder_headers_test() {
+    let headers = XRPClient::builder()
+        .header("X-Api-Key", "first")
+        .header("X-Api-Key", "second")
+        .bearer_token("secret")
+        .default_headers()
+        .unwrap();
+    assert_eq!(headers["x-api-key"], "second");
+    assert_eq!(headers[AUTHORIZATION], "Bearer secret");
+    assert!(XRPClient::builder()
+        .header("Bad Name", "value")
+        .http_client(None)
+        .is_err());
+    assert!(XRPClient::builder()
+        .bearer_token("line\nbreak")
+        .http_client(None)
+        .is_err());
+    assert!(XRPClient::builder()
+        .url("http://localhost:51234")
+        .header("X-Api-Key", "line\nbreak")
+        .build()
+        .is_err());
+}
+
+#[test]
+fn builder_root_certificate_test() {
+    const ROOT_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIBjjCCATOgAwIBAgIUOBgwDEJcMvaJs2Mq2xnG+R07plYwCgYIKoZIzj0EAwIw
+GzEZMBcGA1UEAwwQcmlwcGxlZC5pbnRlcm5hbDAgFw0yNjEwMTUwMjI0NTZaGA8y
+MTI2MDkyMTAyMjQ1NlowGzEZMBcGA1UEAwwQcmlwcGxlZC5pbnRlcm5hbDBZMBMG
+ByqGSM49AgEGCCqGSM49AwEHA0IABEE7vLeQb90l/r/JxwpCxTHtoCdrQgCQYj+B
+TwuPCqzUhMJtWN+YWvfFSvWVvXv2JA6PldMpn535w9sQZ/+eqTSjUzBRMB0GA1Ud
+DgQWBBSoiCl+k0Hoclf848nBSQX5/ipNpDAfBgNVHSMEGDAWgBSoiCl+k0Hoclf8
+48nBSQX5/ipNpDAPBgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0kAMEYCIQCT
+m9n2RcqV3bZkoIrsroZXpzKjof+/SuG9cyX74vX/ewIhAOUAek23kPzAyfWa3mT1
+yHh0ZG0MbLKpJrufyerkFDxj
+-----END CERTIFICATE-----
+";
+    assert!(XRPClient::builder()
+        .root_certificate_pem(ROOT_PEM.as_bytes())
+        .http_client(None)
+        .is_ok());
+    assert!(XRPClient::builder()
+        .root_certificate_pem(b"not a certificate")
+        .http_client(None)
+        .is_err());
+    assert!(XRPClient::builder()
+        .client_identity_pkcs12(b"not an archive", "password")
+        .http_client(None)
+        .is_err());
+    assert!(XRPClient::builder()
+        .url("https://localhost:51234")
+        .root_certificate_pem(ROOT_PEM.as_bytes())
+        .build()
+        .is_ok());
+    assert!(XRPClient::builder()
+        .url("https://localhost:51234")
+        .root_certificate_pem(b"not a certificate")
+        .build()
+        .is_err());
+    let builder = XRPClient::builder().client_identity_pkcs12(b"archive", "hunter2");
+    assert!(!format!("{:?}", builder).contains("hunter2"));
+}
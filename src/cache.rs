@@ -0,0 +1,146 @@
+use crate::{LedgerInfo, LedgerInfoParams, TransactionInfo, TxParams, XRPClient};
+use failure::Error;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// `build_cached` without `cache(capacity)` keeps this many ledgers and as many transactions.
+pub const DEFAULT_CAPACITY: usize = 128;
+
+/// Drops the least recently used entry once `capacity` is reached.
+#[derive(Debug)]
+struct Lru<V> {
+    capacity: usize,
+    entries: HashMap<String, Arc<V>>,
+    order: VecDeque<String>,
+}
+
+impl<V> Lru<V> {
+    fn new(capacity: usize) -> Self {
+        Lru {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<Arc<V>> {
+        let value = self.entries.get(key)?.clone();
+        self.touch(key);
+        Some(value)
+    }
+
+    fn insert(&mut self, key: String, value: Arc<V>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(key.clone(), value).is_some() {
+            self.touch(&key);
+            return;
+        }
+        self.order.push_back(key);
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|entry| entry == key) {
+            let key = self.order.remove(position).unwrap();
+            self.order.push_back(key);
+        }
+    }
+}
+
+/**
+ * Remembers `ledger` and `tx` results that can no longer change: only validated ones,
+ * and for `ledger` only when the params name the ledger by hash or sequence number,
+ * never `current`, `closed` or `validated`. A hit answers without any request.
+ */
+pub struct CachedClient {
+    client: Arc<XRPClient>,
+    ledgers: Mutex<Lru<LedgerInfo>>,
+    transactions: Mutex<Lru<TransactionInfo>>,
+}
+
+impl std::fmt::Debug for CachedClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CachedClient")
+            .field("ledgers", &self.ledgers.lock().unwrap().entries.len())
+            .field("transactions", &self.transactions.lock().unwrap().entries.len())
+            .finish()
+    }
+}
+
+/// The params as json, so `full: true` and `full: false` of the same ledger are kept apart.
+fn key<P: Serialize>(params: &P) -> Result<String, Error> {
+    Ok(serde_json::to_string(params)?)
+}
+
+impl CachedClient {
+    pub fn new(client: Arc<XRPClient>, capacity: usize) -> Self {
+        CachedClient {
+            client,
+            ledgers: Mutex::new(Lru::new(capacity)),
+            transactions: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// The client underneath, for every call that is not cached.
+    pub fn client(&self) -> &XRPClient {
+        &self.client
+    }
+
+    pub fn ledger(&self, params: LedgerInfoParams) -> Result<Arc<LedgerInfo>, Error> {
+        let immutable = params.ledger_hash.is_some()
+            || matches!(
+                params.ledger_index,
+                Some(crate::RequestLedgerIndex::Number { .. })
+            );
+        if !immutable {
+            return self.client.ledger(params).map(Arc::new);
+        }
+        let key = key(&params)?;
+        if let Some(ledger) = self.ledgers.lock().unwrap().get(&key) {
+            return Ok(ledger);
+        }
+        let ledger = Arc::new(self.client.ledger(params)?);
+        if ledger.validated {
+            self.ledgers.lock().unwrap().insert(key, ledger.clone());
+        }
+        Ok(ledger)
+    }
+
+    pub fn tx(&self, params: TxParams) -> Result<Arc<TransactionInfo>, Error> {
+        let key = key(&params)?;
+        if let Some(transaction) = self.transactions.lock().unwrap().get(&key) {
+            return Ok(transaction);
+        }
+        let transaction = Arc::new(self.client.tx(params)?);
+        if transaction.validated == Some(true) {
+            self.transactions
+                .lock()
+                .unwrap()
+                .insert(key, transaction.clone());
+        }
+        Ok(transaction)
+    }
+}
+
+#[test]
+fn lru_eviction_test() {
+    let mut lru = Lru::new(2);
+    lru.insert("a".into(), Arc::new(1));
+    lru.insert("b".into(), Arc::new(2));
+    assert_eq!(lru.get("a").map(|value| *value), Some(1));
+    lru.insert("c".into(), Arc::new(3));
+    assert!(lru.get("b").is_none());
+    assert_eq!(lru.get("a").map(|value| *value), Some(1));
+    assert_eq!(lru.get("c").map(|value| *value), Some(3));
+
+    let mut disabled = Lru::new(0);
+    disabled.insert("a".into(), Arc::new(1));
+    assert!(disabled.get("a").is_none());
+}
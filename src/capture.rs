@@ -0,0 +1,158 @@
+use crate::Envelope;
+use failure::Error;
+use serde::de::DeserializeOwned;
+use serde_json::value::Value;
+use std::fmt;
+
+/// How much of the body a `DecodeError` message shows, `raw` always has all of it.
+pub const SNIPPET_LEN: usize = 512;
+
+/// A response the typed structs could not read, usually a field rippled added or changed.
+#[derive(Debug, Clone)]
+pub struct DecodeError {
+    pub method: String,
+    pub message: String,
+    pub raw: Value,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let body = self.raw.to_string();
+        let snippet: String = body.chars().take(SNIPPET_LEN).collect();
+        let ellipsis = if snippet.len() < body.len() { "..." } else { "" };
+        write!(
+            f,
+            "Could not decode the {} response: {}, body: {}{}",
+            self.method, self.message, snippet, ellipsis
+        )
+    }
+}
+
+impl failure::Fail for DecodeError {}
+
+/// `result` of a `method` reply as `T`, failures downcast to `DecodeError`.
+pub fn decode<T: DeserializeOwned>(method: &str, result: Value) -> Result<Envelope<T>, Error> {
+    match serde_json::from_value(result.clone()) {
+        Ok(response) => Ok(response),
+        Err(error) => Err(DecodeError {
+            method: method.into(),
+            message: error.to_string(),
+            raw: result,
+        }
+        .into()),
+    }
+}
+
+/// How `XRPClient` numbers its requests, `request_with_id` picks the id itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdStrategy {
+    /// 1, 2, 3 and so on, per client.
+    Monotonic,
+    /// A random version 4 uuid string for every request.
+    Uuid,
+}
+
+/// A reply whose `id` is not the one sent, e.g. a proxy mixing up connections.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdMismatch {
+    pub sent: Value,
+    pub received: Value,
+}
+
+impl fmt::Display for IdMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Sent request id {} but the reply is for {}",
+            self.sent, self.received
+        )
+    }
+}
+
+impl failure::Fail for IdMismatch {}
+
+/// Servers and proxies that do not echo the `id` are trusted, only another `id` fails.
+pub(crate) fn check_id(sent: &Value, reply: &Value) -> Result<(), IdMismatch> {
+    let received = &reply["id"];
+    if received.is_null() || received == sent {
+        Ok(())
+    } else {
+        Err(IdMismatch {
+            sent: sent.clone(),
+            received: received.clone(),
+        })
+    }
+}
+
+pub(crate) fn uuid() -> String {
+    let bytes: [u8; 16] = rand::random();
+    let hex = hex::encode(bytes);
+    // version 4, variant 10xx
+    let variant = "89ab".as_bytes()[(bytes[8] & 3) as usize] as char;
+    format!(
+        "{}-{}-4{}-{}{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[13..16],
+        variant,
+        &hex[17..20],
+        &hex[20..32]
+    )
+}
+
+#[test]
+fn decode_error_message_test() {
+    let raw = serde_json::json!({
+        "account_data": { "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "Balance": [] },
+        "status": "success"
+    });
+    let error = decode::<crate::AccountInfo>("account_info", raw.clone()).unwrap_err();
+    let decode_error = error.downcast_ref::<DecodeError>().unwrap();
+    assert_eq!(decode_error.method, "account_info");
+    assert_eq!(decode_error.raw, raw);
+    let message = error.to_string();
+    assert!(message.contains("account_info"));
+    assert!(message.contains(r#""Balance":[]"#));
+}
+
+#[test]
+fn decode_error_truncated_test() {
+    let error = DecodeError {
+        method: "ledger_data".into(),
+        message: "missing field `state`".into(),
+        raw: serde_json::json!({ "blob": "A".repeat(2 * SNIPPET_LEN) }),
+    };
+    let message = error.to_string();
+    assert!(message.ends_with("..."));
+    assert!(message.len() < SNIPPET_LEN + 100);
+}
+
+#[test]
+fn check_id_test() {
+    let reply = serde_json::json!({ "id": "export-7", "result": {} });
+    assert!(check_id(&serde_json::json!("export-7"), &reply).is_ok());
+    let mismatch = check_id(&serde_json::json!("export-8"), &reply).unwrap_err();
+    assert_eq!(mismatch.received, serde_json::json!("export-7"));
+    assert_eq!(
+        mismatch.to_string(),
+        r#"Sent request id "export-8" but the reply is for "export-7""#
+    );
+}
+
+#[test]
+fn check_id_missing_test() {
+    let reply = serde_json::json!({ "result": {} });
+    assert!(check_id(&serde_json::json!("export-7"), &reply).is_ok());
+    let reply = serde_json::json!({ "id": null, "result": {} });
+    assert!(check_id(&serde_json::json!(7), &reply).is_ok());
+}
+
+#[test]
+fn uuid_format_test() {
+    let id = uuid();
+    let groups: Vec<usize> = id.split('-').map(str::len).collect();
+    assert_eq!(groups, vec![8, 4, 4, 4, 12]);
+    assert_eq!(&id[14..15], "4");
+    assert!("89ab".contains(&id[19..20]));
+    assert_ne!(uuid(), id);
+}
@@ -0,0 +1,312 @@
+use crate::capture::{check_id, decode, uuid, IdStrategy};
+use crate::metrics::{error_code, Metrics};
+use crate::pool::Pool;
+use crate::retry::RetryPolicy;
+use crate::throttle::Throttle;
+use crate::timeout::{TimedOut, TimeoutPolicy};
+use crate::*;
+use failure::Error;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::value::Value;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+/**
+ * A rippled json rpc client. Every call waits for the throttle first, so one client
+ * shared between threads keeps all of them within a single budget.
+ */
+pub struct XRPClient {
+    url: String,
+    user: Option<String>,
+    pass: Option<String>,
+    pool: Pool,
+    throttle: Throttle,
+    next_id: AtomicU64,
+    pub(crate) ids: IdStrategy,
+    pub(crate) capture_raw: bool,
+    pub(crate) api_version: Option<u32>,
+    pub(crate) retry: Option<RetryPolicy>,
+    pub(crate) timeouts: Option<TimeoutPolicy>,
+    pub(crate) metrics: Option<Arc<dyn Metrics + Send + Sync>>,
+}
+
+impl fmt::Debug for XRPClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("XRPClient").field("url", &self.url).finish()
+    }
+}
+
+impl XRPClient {
+    /**
+     * At most `max_concurrency` calls in flight and `requests_per_sec` calls per second,
+     * 0 for no limit. The last argument is unused, kept so existing callers still compile.
+     */
+    pub fn new(
+        url: String,
+        user: Option<String>,
+        pass: Option<String>,
+        max_concurrency: usize,
+        requests_per_sec: f64,
+        _max_batch_size: usize,
+    ) -> XRPClient {
+        let connect = |_| Ok::<_, String>(reqwest::Client::new());
+        XRPClient::from_parts(
+            url,
+            user,
+            pass,
+            Pool::new(Box::new(connect), None).expect("Building the http client"),
+            Throttle::new(max_concurrency, requests_per_sec, 1),
+        )
+    }
+
+    pub(crate) fn from_parts(
+        url: String,
+        user: Option<String>,
+        pass: Option<String>,
+        pool: Pool,
+        throttle: Throttle,
+    ) -> XRPClient {
+        XRPClient {
+            url,
+            user,
+            pass,
+            pool,
+            throttle,
+            next_id: AtomicU64::new(1),
+            ids: IdStrategy::Monotonic,
+            capture_raw: false,
+            api_version: None,
+            retry: None,
+            timeouts: None,
+            metrics: None,
+        }
+    }
+
+    fn next_id(&self) -> Value {
+        match self.ids {
+            IdStrategy::Monotonic => json!(self.next_id.fetch_add(1, Ordering::Relaxed)),
+            IdStrategy::Uuid => json!(uuid()),
+        }
+    }
+
+    /**
+     * Posts a json rpc body holding a call for each of `methods`, each taking a token of the
+     * rate limit. It gets the longest timeout of those methods.
+     */
+    pub(crate) fn post(&self, body: &Value, methods: &[&str]) -> Result<Value, Error> {
+        let timeout = self
+            .timeouts
+            .as_ref()
+            .and_then(|timeouts| methods.iter().map(|&method| timeouts.timeout(method)).max());
+        let _permit = self.throttle.acquire(methods.len());
+        let mut post = self.pool.client(timeout)?.post(&self.url).json(body);
+        if let Some(user) = &self.user {
+            post = post.basic_auth(user, self.pass.as_ref());
+        }
+        let reply = post
+            .send()
+            .and_then(reqwest::Response::error_for_status)
+            .and_then(|mut response| response.json::<Value>());
+        self.pool.release();
+        match (reply, timeout) {
+            (Err(ref error), Some(timeout)) if error.is_timeout() => Err(TimedOut {
+                method: match methods {
+                    [method] => (*method).into(),
+                    _ => "batch".into(),
+                },
+                timeout,
+            }
+            .into()),
+            (reply, _) => Ok(reply?),
+        }
+    }
+
+    /// `params` as json, with the `api_version` the client was built with.
+    pub(crate) fn params<P: Serialize>(&self, params: &P) -> Result<Value, Error> {
+        let mut params = serde_json::to_value(params)?;
+        if let (Some(api_version), Value::Object(fields)) = (self.api_version, &mut params) {
+            fields.insert("api_version".into(), api_version.into());
+        }
+        Ok(params)
+    }
+
+    /**
+     * Any rpc by name, `params` being its params struct, e.g. `AccountInfoParams`.
+     * Unlike the typed methods it hands back the `warnings`, the request `id` and, with
+     * `capture_raw` on the builder, the `result` object next to the typed result.
+     */
+    pub fn request<T, P>(&self, method: &str, params: &P) -> Result<Envelope<T>, Error>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        self.request_with_id(method, params, self.next_id())
+    }
+
+    /**
+     * `request` with an id of the caller's choosing, e.g. to correlate logs. A reply
+     * carrying another id fails with `IdMismatch`. Retries, under the policy given to
+     * `XRPClientBuilder::retry`, are sent with the same id.
+     */
+    pub fn request_with_id<T, P>(
+        &self,
+        method: &str,
+        params: &P,
+        id: Value,
+    ) -> Result<Envelope<T>, Error>
+    where
+        T: DeserializeOwned,
+        P: Serialize,
+    {
+        let call = json!({
+            "id": id,
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": [self.params(params)?],
+        });
+        #[cfg(feature = "tracing")]
+        let span = crate::trace::span(method, &self.url);
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+        let start = Instant::now();
+        let envelope = self.retried(method, &call, &id);
+        let latency = start.elapsed();
+        let failure: Option<(&str, &dyn fmt::Display)> = match &envelope {
+            Ok(Envelope {
+                response: XrpResponse::Error(error),
+                ..
+            }) => Some((error.code(), error)),
+            Ok(_) => None,
+            Err(error) => Some((error_code(error), error)),
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics.record(method, latency, failure.map(|(code, _)| code));
+        }
+        #[cfg(feature = "tracing")]
+        crate::trace::record(&span, latency, failure);
+        envelope
+    }
+
+    /// `exchange`, again and again for as long as the retry policy asks for it.
+    fn retried<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        call: &Value,
+        id: &Value,
+    ) -> Result<Envelope<T>, Error> {
+        let mut attempt = 0;
+        loop {
+            let envelope = self.exchange(method, call, id);
+            let retry = match (&self.retry, &envelope) {
+                (
+                    Some(policy),
+                    Ok(Envelope {
+                        response: XrpResponse::Error(error),
+                        ..
+                    }),
+                ) => {
+                    attempt += 1;
+                    policy.retry_after(method, &error.clone().into(), attempt)
+                }
+                (Some(policy), Err(error)) => {
+                    attempt += 1;
+                    policy.retry_after(method, error, attempt)
+                }
+                _ => None,
+            };
+            match retry {
+                Some(delay) => thread::sleep(delay),
+                None => return envelope,
+            }
+        }
+    }
+
+    /// One round trip of `call`.
+    fn exchange<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        call: &Value,
+        id: &Value,
+    ) -> Result<Envelope<T>, Error> {
+        let mut reply = self.post(call, &[method])?;
+        check_id(id, &reply)?;
+        let result = reply["result"].take();
+        let raw = if self.capture_raw {
+            Some(result.clone())
+        } else {
+            None
+        };
+        let mut envelope = decode(method, result)?;
+        envelope.id = id.clone();
+        envelope.raw = raw;
+        Ok(envelope)
+    }
+}
+
+macro_rules! rpc_method {
+    ($method:ident() -> $result:ty) => {
+        pub fn $method(&self) -> Result<$result, Error> {
+            self.request(stringify!($method), &json!({}))?.into_result()
+        }
+    };
+    ($method:ident($params:ty) -> $result:ty) => {
+        pub fn $method(&self, params: $params) -> Result<$result, Error> {
+            self.request(stringify!($method), &params)?.into_result()
+        }
+    };
+}
+
+/// One method per rpc, named like it and taking its params struct if it has one.
+macro_rules! rpc_methods {
+    ($($method:ident($($params:ty)?) -> $result:ty;)*) => {
+        impl XRPClient {
+            $(rpc_method!($method($($params)?) -> $result);)*
+        }
+    };
+}
+
+rpc_methods! {
+    account_info(AccountInfoParams) -> AccountInfo;
+    account_tx(AccountTxParams) -> AccountTx;
+    ledger(LedgerInfoParams) -> LedgerInfo;
+    ledger_current() -> LedgerCurrent;
+    ledger_closed() -> LedgerClosed;
+    server_info() -> ServerInfo;
+    fee() -> FeeInfo;
+    tx(TxParams) -> TransactionInfo;
+    account_lines(AccountLinesParams) -> AccountLines;
+    account_objects(AccountObjectsParams) -> AccountObjects;
+    book_offers(BookOffersParams) -> BookOffers;
+    submit(SubmitParams) -> SubmitResult;
+    gateway_balances(GatewayBalancesParams) -> GatewayBalances;
+    account_currencies(AccountCurrenciesParams) -> AccountCurrencies;
+    ripple_path_find(RipplePathFindParams) -> RipplePathFind;
+    ledger_data(LedgerDataParams) -> LedgerData;
+    ledger_entry(LedgerEntryParams) -> LedgerEntry;
+    noripple_check(NoRippleCheckParams) -> NoRippleCheck;
+    deposit_authorized(DepositAuthorizedParams) -> DepositAuthorized;
+    transaction_entry(TransactionEntryParams) -> TransactionEntry;
+    server_state() -> ServerStateInfo;
+    account_offers(AccountOffersParams) -> AccountOffers;
+    channel_authorize(ChannelAuthorizeParams) -> ChannelAuthorize;
+    channel_verify(ChannelVerifyParams) -> ChannelVerify;
+    account_channels(AccountChannelsParams) -> AccountChannels;
+    account_nfts(AccountNftsParams) -> AccountNfts;
+    nft_buy_offers(NftOffersParams) -> NftOffers;
+    nft_sell_offers(NftOffersParams) -> NftOffers;
+    manifest(ManifestParams) -> Manifest;
+    ping() -> Ping;
+    random() -> Random;
+    submit_multisigned(SubmitMultisignedParams) -> SubmitResult;
+    tx_history(TxHistoryParams) -> TxHistory;
+    feature(FeatureParams) -> FeatureInfo;
+    get_aggregate_price(GetAggregatePriceParams) -> AggregatePrice;
+    amm_info(AmmInfoParams) -> AmmInfo;
+    sign(SignParams) -> SignResult;
+    sign_for(SignForParams) -> SignForResult;
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+const CURRENCY_CODE_BYTES: usize = 20;
+const STANDARD_CODE_OFFSET: usize = 12;
+const STANDARD_CODE_LENGTH: usize = 3;
+const STANDARD_CODE_CHARS: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789?!@#$%^&*<>(){}[]|";
+
+/**
+ * Either a 3 character code or 160 bits written out as 40 hex characters. [1]
+ * Hex codes laid out like the standard format are normalized to `Standard`.
+ * 1: https://xrpl.org/currency-formats.html#currency-codes
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Xrp,
+    Standard(String),
+    Hex(String),
+}
+
+fn is_standard_code(code: &str) -> bool {
+    code.len() == STANDARD_CODE_LENGTH && code.chars().all(|c| STANDARD_CODE_CHARS.contains(c))
+}
+
+impl Currency {
+    /// Issued currencies may not use `XRP`, not even in its hex form.
+    pub fn issued(code: &str) -> Result<Currency, String> {
+        match code.parse()? {
+            Currency::Xrp => Err(format!(
+                "{:?} is reserved for XRP and can not be issued",
+                code
+            )),
+            currency => Ok(currency),
+        }
+    }
+
+    pub fn as_hex(&self) -> String {
+        match self {
+            Currency::Xrp => "0".repeat(CURRENCY_CODE_BYTES * 2),
+            Currency::Standard(code) => {
+                let mut bytes = [0u8; CURRENCY_CODE_BYTES];
+                bytes[STANDARD_CODE_OFFSET..STANDARD_CODE_OFFSET + STANDARD_CODE_LENGTH]
+                    .copy_from_slice(code.as_bytes());
+                hex::encode_upper(bytes)
+            }
+            Currency::Hex(code) => code.clone(),
+        }
+    }
+
+    /// Non standard codes that spell out ASCII (like `RLUSD`) are shown as text, anything else as hex.
+    pub fn as_human(&self) -> String {
+        match self {
+            Currency::Xrp => "XRP".into(),
+            Currency::Standard(code) => code.clone(),
+            Currency::Hex(code) => {
+                let bytes = hex::decode(code).unwrap_or_default();
+                let end = bytes
+                    .iter()
+                    .rposition(|&byte| byte != 0)
+                    .map_or(0, |last| last + 1);
+                let text = &bytes[..end];
+                if !text.is_empty() && text.iter().all(|byte| (0x20..0x7f).contains(byte)) {
+                    String::from_utf8_lossy(text).into_owned()
+                } else {
+                    code.clone()
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "XRP" {
+            return Ok(Currency::Xrp);
+        }
+        if is_standard_code(s) {
+            return Ok(Currency::Standard(s.into()));
+        }
+        let bytes = hex::decode(s)
+            .ok()
+            .filter(|bytes| bytes.len() == CURRENCY_CODE_BYTES)
+            .ok_or_else(|| {
+                format!(
+                    "{:?} is neither a 3 character nor a 40 hex character currency code",
+                    s
+                )
+            })?;
+        if bytes.iter().all(|&byte| byte == 0) {
+            return Ok(Currency::Xrp);
+        }
+        let standard_layout = bytes[..STANDARD_CODE_OFFSET].iter().all(|&byte| byte == 0)
+            && bytes[STANDARD_CODE_OFFSET + STANDARD_CODE_LENGTH..]
+                .iter()
+                .all(|&byte| byte == 0);
+        if standard_layout {
+            let code = String::from_utf8_lossy(
+                &bytes[STANDARD_CODE_OFFSET..STANDARD_CODE_OFFSET + STANDARD_CODE_LENGTH],
+            )
+            .into_owned();
+            if code == "XRP" {
+                return Err(format!("{:?} is the disallowed hex encoding of XRP", s));
+            }
+            if is_standard_code(&code) {
+                return Ok(Currency::Standard(code));
+            }
+        }
+        Ok(Currency::Hex(s.to_uppercase()))
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Currency::Xrp => f.write_str("XRP"),
+            Currency::Standard(code) | Currency::Hex(code) => f.write_str(code),
+        }
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+pub(crate) fn deserialize_issued<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let code = String::deserialize(deserializer)?;
+    Currency::issued(&code).map_err(serde::de::Error::custom)
+}
+
+#[test]
+fn currency_standard_round_trip_test() {
+    let usd: Currency = "USD".parse().unwrap();
+    assert_eq!(usd, Currency::Standard("USD".into()));
+    assert_eq!(usd.as_hex(), "0000000000000000000000005553440000000000");
+    let from_hex: Currency = usd.as_hex().parse().unwrap();
+    assert_eq!(from_hex, usd);
+    assert_eq!(from_hex.as_human(), "USD");
+    assert_eq!(serde_json::to_string(&from_hex).unwrap(), r#""USD""#);
+}
+
+#[test]
+fn currency_hex_round_trip_test() {
+    let ascii: Currency = "524C555344000000000000000000000000000000".parse().unwrap();
+    assert_eq!(
+        ascii,
+        Currency::Hex("524C555344000000000000000000000000000000".into())
+    );
+    assert_eq!(ascii.as_human(), "RLUSD");
+    assert_eq!(ascii.as_hex(), "524C555344000000000000000000000000000000");
+
+    let binary: Currency = "015841551a748ad2c1f76ff6ecb0cccd00000000".parse().unwrap();
+    assert_eq!(binary.as_human(), "015841551A748AD2C1F76FF6ECB0CCCD00000000");
+    let round_trip: Currency = serde_json::from_str(&serde_json::to_string(&binary).unwrap()).unwrap();
+    assert_eq!(round_trip, binary);
+}
+
+#[test]
+fn currency_issued_xrp_test() {
+    assert_eq!("XRP".parse::<Currency>(), Ok(Currency::Xrp));
+    assert!(Currency::issued("XRP").is_err());
+    assert!("0000000000000000000000005852500000000000"
+        .parse::<Currency>()
+        .is_err());
+    assert!(Currency::issued("USD").is_ok());
+}
@@ -0,0 +1,223 @@
+use serde::de::{DeserializeOwned, Deserializer, Error as _};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/**
+ * rippled reports failures inside `result` with `status: "error"`. [1]
+ * 1: https://xrpl.org/error-formatting.html
+ */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct XrpError {
+    pub error: String,
+    pub error_code: Option<i64>,
+    pub error_message: Option<String>,
+    pub request: Option<serde_json::Value>,
+    pub status: Option<String>,
+}
+
+impl XrpError {
+    /// Accepts either the whole JSON-RPC body or just its `result`.
+    pub fn from_response(response: &serde_json::Value) -> Option<XrpError> {
+        let result = response.get("result").unwrap_or(response);
+        result.get("error")?;
+        serde_json::from_value(result.clone()).ok()
+    }
+
+    /// The error token, e.g. `actNotFound`.
+    pub fn code(&self) -> &str {
+        &self.error
+    }
+}
+
+impl fmt::Display for XrpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.error_message {
+            Some(ref message) => write!(f, "{}: {}", self.error, message),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl failure::Fail for XrpError {}
+
+/**
+ * One entry of the `warnings` rippled adds to a result, e.g. 1001 when the server is
+ * amendment blocked, 1002 when an amendment it does not know is about to pass and 1003
+ * for a reporting mode server, which is not authoritative for the open ledger. [1]
+ * 1: https://xrpl.org/response-formatting.html#api-warnings
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub id: u32,
+    pub message: String,
+}
+
+/**
+ * A `result` as rippled sends it: either its error object or the typed result. `XRPClient`
+ * calls go through `into_result`, so their errors downcast to `XrpError`.
+ */
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum XrpResponse<T> {
+    Error(XrpError),
+    Success(T),
+}
+
+impl<T> XrpResponse<T> {
+    pub fn into_result(self) -> Result<T, failure::Error> {
+        match self {
+            XrpResponse::Error(error) => Err(error.into()),
+            XrpResponse::Success(result) => Ok(result),
+        }
+    }
+}
+
+/**
+ * The response together with what rippled may add to any `result`: the api `warnings`,
+ * and a `warning` of "load" once the client is close to being rate limited by the node. [1]
+ * 1: https://xrpl.org/response-formatting.html
+ */
+#[derive(Debug)]
+pub struct Envelope<T> {
+    pub response: XrpResponse<T>,
+    /// `None` when absent, and also when they are not in the documented shape.
+    pub warnings: Option<Vec<Warning>>,
+    pub warning: Option<String>,
+    /// The json rpc `id` of the reply, `Null` for an envelope read from a bare `result`.
+    pub id: serde_json::Value,
+    /// The whole `result` object, kept only by clients built with `capture_raw`.
+    pub raw: Option<serde_json::Value>,
+}
+
+impl<T> Envelope<T> {
+    pub fn into_result(self) -> Result<T, failure::Error> {
+        self.response.into_result()
+    }
+
+    /// Slow down: the node will soon answer `slowDown` to this client.
+    pub fn is_load_warning(&self) -> bool {
+        self.warning.as_deref() == Some("load")
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Envelope<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let result = serde_json::Value::deserialize(deserializer)?;
+        let warnings = result
+            .get("warnings")
+            .and_then(|warnings| Vec::<Warning>::deserialize(warnings).ok());
+        let warning = result
+            .get("warning")
+            .and_then(serde_json::Value::as_str)
+            .map(String::from);
+        let error = match result.get("error") {
+            Some(_) => XrpError::deserialize(&result).ok(),
+            None => None,
+        };
+        // not through the untagged `XrpResponse`, whose error would hide what `T` missed
+        let response = match error {
+            Some(error) => XrpResponse::Error(error),
+            None => XrpResponse::Success(T::deserialize(result).map_err(D::Error::custom)?),
+        };
+        Ok(Envelope {
+            response,
+            warnings,
+            warning,
+            id: serde_json::Value::Null,
+            raw: None,
+        })
+    }
+}
+
+#[test]
+fn xrp_error_from_response_test() {
+    let response = serde_json::json!({
+        "result": {
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "error": "actNotFound",
+            "error_code": 19,
+            "error_message": "Account not found.",
+            "ledger_current_index": 56867201,
+            "request": {
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "command": "account_info",
+                "ledger_index": "current",
+                "strict": true
+            },
+            "status": "error",
+            "validated": false
+        }
+    });
+    let error = XrpError::from_response(&response).unwrap();
+    assert_eq!(error.code(), "actNotFound");
+    assert_eq!(error.error_code, Some(19));
+    assert_eq!(error.to_string(), "actNotFound: Account not found.");
+    assert!(XrpError::from_response(&serde_json::json!({ "status": "success" })).is_none());
+}
+
+#[test]
+fn xrp_response_downcast_test() {
+    let response: XrpResponse<serde_json::Value> = serde_json::from_str(
+        r#"{
+  "error": "lgrNotFound",
+  "error_code": 21,
+  "error_message": "ledgerNotFound",
+  "status": "error"
+}"#,
+    )
+    .unwrap();
+    let error = response.into_result().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<XrpError>().map(XrpError::code),
+        Some("lgrNotFound")
+    );
+
+    let response: XrpResponse<serde_json::Value> =
+        serde_json::from_str(r#"{ "status": "success" }"#).unwrap();
+    assert!(response.into_result().is_ok());
+}
+
+#[test]
+fn envelope_warnings_test() {
+    let envelope: Envelope<serde_json::Value> = serde_json::from_str(
+        r#"{
+  "ledger_current_index": 56867201,
+  "status": "success",
+  "warnings": [
+    {
+      "id": 1003,
+      "message": "This is a reporting server.  The default ledger range is \"validated\"."
+    }
+  ]
+}"#,
+    )
+    .unwrap();
+    let warnings = envelope.warnings.as_ref().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].id, 1003);
+    assert!(warnings[0]
+        .message
+        .starts_with("This is a reporting server"));
+    assert!(!envelope.is_load_warning());
+    assert!(envelope.into_result().is_ok());
+
+    let envelope: Envelope<serde_json::Value> =
+        serde_json::from_str(r#"{ "status": "success", "warning": "load" }"#).unwrap();
+    assert!(envelope.warnings.is_none());
+    assert!(envelope.is_load_warning());
+
+    let envelope: Envelope<serde_json::Value> =
+        serde_json::from_str(r#"{ "status": "success", "warnings": "reporting mode" }"#).unwrap();
+    assert!(envelope.warnings.is_none());
+    assert!(envelope.into_result().is_ok());
+
+    let envelope: Envelope<serde_json::Value> = serde_json::from_str(
+        r#"{ "error": "tooBusy", "status": "error", "warnings": [{ "id": 1001 }] }"#,
+    )
+    .unwrap();
+    assert!(envelope.warnings.is_none());
+    assert!(matches!(envelope.response, XrpResponse::Error(_)));
+}
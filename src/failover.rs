@@ -0,0 +1,123 @@
+use crate::retry::TRANSIENT_ERRORS;
+use crate::{XRPClient, XrpError};
+use failure::{format_err, Error};
+use rand::seq::SliceRandom;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The order in which `FailoverClient` tries its endpoints for each call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FailoverStrategy {
+    /// Every call starts at the endpoint after the one the previous call started at.
+    RoundRobin,
+    /// Always the first endpoint, the others only when it is down.
+    PrimaryWithFallback,
+    /// A fresh random order for every call.
+    Random,
+}
+
+/// Connection failures, 5xx answers and rippled being busy or out of sync, never errors like `actNotFound`.
+pub fn is_endpoint_failure(error: &Error) -> bool {
+    if let Some(xrp_error) = error.downcast_ref::<XrpError>() {
+        return TRANSIENT_ERRORS.contains(&xrp_error.code());
+    }
+    if let Some(http_error) = error.downcast_ref::<reqwest::Error>() {
+        return match http_error.status() {
+            Some(status) => status.is_server_error(),
+            None => true,
+        };
+    }
+    false
+}
+
+/**
+ * Several rippled nodes behind one client, moving on to the next node when a call
+ * fails for a reason that is the node's fault (see `is_endpoint_failure`).
+ * Each endpoint keeps its own throttle.
+ */
+pub struct FailoverClient {
+    clients: Vec<Arc<XRPClient>>,
+    strategy: FailoverStrategy,
+    next: AtomicUsize,
+}
+
+impl std::fmt::Debug for FailoverClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("FailoverClient")
+            .field("endpoints", &self.clients.len())
+            .field("strategy", &self.strategy)
+            .finish()
+    }
+}
+
+impl FailoverClient {
+    pub fn new(clients: Vec<Arc<XRPClient>>, strategy: FailoverStrategy) -> Result<Self, String> {
+        if clients.is_empty() {
+            return Err("FailoverClient needs at least one endpoint".into());
+        }
+        Ok(FailoverClient {
+            clients,
+            strategy,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    fn order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.clients.len()).collect();
+        match self.strategy {
+            FailoverStrategy::RoundRobin => {
+                let start = self.next.fetch_add(1, Ordering::Relaxed) % order.len();
+                order.rotate_left(start);
+            }
+            FailoverStrategy::PrimaryWithFallback => (),
+            FailoverStrategy::Random => order.shuffle(&mut rand::thread_rng()),
+        }
+        order
+    }
+
+    /// `client.account_info(..)` and friends wrapped as `call`, tried on each endpoint until one answers.
+    pub fn call<T, F>(&self, mut call: F) -> Result<T, Error>
+    where
+        F: FnMut(&XRPClient) -> Result<T, Error>,
+    {
+        let mut last_error = None;
+        for index in self.order() {
+            match call(&self.clients[index]) {
+                Ok(result) => return Ok(result),
+                Err(error) if is_endpoint_failure(&error) => last_error = Some(error),
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| format_err!("No endpoint answered")))
+    }
+}
+
+#[test]
+fn failover_order_test() {
+    let clients: Vec<_> = (0..3)
+        .map(|port| {
+            Arc::new(XRPClient::new(
+                format!("http://127.0.0.1:{}/", port + 1),
+                None,
+                None,
+                0,
+                0.0,
+                0,
+            ))
+        })
+        .collect();
+    let round_robin = FailoverClient::new(clients.clone(), FailoverStrategy::RoundRobin).unwrap();
+    assert_eq!(round_robin.order(), vec![0, 1, 2]);
+    assert_eq!(round_robin.order(), vec![1, 2, 0]);
+    assert_eq!(round_robin.order(), vec![2, 0, 1]);
+
+    let primary = FailoverClient::new(clients.clone(), FailoverStrategy::PrimaryWithFallback).unwrap();
+    assert_eq!(primary.order(), vec![0, 1, 2]);
+    assert_eq!(primary.order(), vec![0, 1, 2]);
+
+    let mut random = FailoverClient::new(clients, FailoverStrategy::Random).unwrap().order();
+    random.sort();
+    assert_eq!(random, vec![0, 1, 2]);
+
+    assert!(FailoverClient::new(Vec::new(), FailoverStrategy::RoundRobin).is_err());
+}
@@ -0,0 +1,92 @@
+use crate::{Drops, FeeDrops, XRPClient};
+use bigdecimal::BigDecimal;
+use failure::{format_err, Error};
+use std::str::FromStr;
+
+/// What `suggest_fee` pays at most, 0.1 XRP, far above the usual 10 drops.
+pub const DEFAULT_FEE_CEILING: Drops = Drops(100_000);
+
+/**
+ * `max(minimum_fee, open_ledger_fee * safety_factor)` in whole drops, rounded up,
+ * and never above `ceiling` even when the minimum is. [1]
+ * 1: https://xrpl.org/transaction-cost.html#current-transaction-cost
+ */
+pub fn suggested_fee(
+    drops: &FeeDrops,
+    safety_factor: f64,
+    ceiling: Drops,
+) -> Result<BigDecimal, String> {
+    if !safety_factor.is_finite() || safety_factor <= 0.0 {
+        return Err(format!("{} is not a valid safety factor", safety_factor));
+    }
+    let factor =
+        BigDecimal::from_str(&safety_factor.to_string()).map_err(|error| error.to_string())?;
+    let scaled = BigDecimal::from(drops.open_ledger_fee.0) * factor;
+    let mut fee = scaled.with_scale(0);
+    if fee < scaled {
+        fee += BigDecimal::from(1);
+    }
+    let minimum = BigDecimal::from(drops.minimum_fee.0);
+    if fee < minimum {
+        fee = minimum;
+    }
+    let ceiling = BigDecimal::from(ceiling.0);
+    Ok(if fee > ceiling { ceiling } else { fee })
+}
+
+impl XRPClient {
+    /// `suggested_fee` for the current `fee` reply, capped at `DEFAULT_FEE_CEILING`.
+    pub fn suggest_fee(&self, safety_factor: f64) -> Result<BigDecimal, Error> {
+        self.suggest_fee_capped(safety_factor, DEFAULT_FEE_CEILING)
+    }
+
+    pub fn suggest_fee_capped(
+        &self,
+        safety_factor: f64,
+        ceiling: Drops,
+    ) -> Result<BigDecimal, Error> {
+        let fee = self.fee()?;
+        suggested_fee(&fee.drops, safety_factor, ceiling)
+            .map_err(|error| format_err!("{}", error))
+    }
+}
+
+#[test]
+fn suggested_fee_high_load_test() {
+    let fee: crate::FeeInfo = serde_json::from_str(
+        r#"{
+  "current_ledger_size": "126",
+  "current_queue_size": "48",
+  "drops": {
+    "base_fee": "10",
+    "median_fee": "5000",
+    "minimum_fee": "12",
+    "open_ledger_fee": "2653"
+  },
+  "expected_ledger_size": "80",
+  "ledger_current_index": 56867210,
+  "levels": {
+    "median_level": "128000",
+    "minimum_level": "307",
+    "open_ledger_level": "67916",
+    "reference_level": "256"
+  },
+  "max_queue_size": "1600",
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        suggested_fee(&fee.drops, 1.5, DEFAULT_FEE_CEILING).unwrap(),
+        BigDecimal::from(3980)
+    );
+    assert_eq!(
+        suggested_fee(&fee.drops, 1.5, Drops(3000)).unwrap(),
+        BigDecimal::from(3000)
+    );
+    assert_eq!(
+        suggested_fee(&fee.drops, 0.001, DEFAULT_FEE_CEILING).unwrap(),
+        BigDecimal::from(12)
+    );
+    assert!(suggested_fee(&fee.drops, -1.0, DEFAULT_FEE_CEILING).is_err());
+}
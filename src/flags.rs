@@ -0,0 +1,140 @@
+use crate::{AccountData, NestedLedgerInfo, TransactionInfo};
+use bitflags::bitflags;
+use num_traits::ToPrimitive;
+
+bitflags! {
+    /**
+     * `lsf` flags of an AccountRoot ledger object. [1]
+     * 1: https://xrpl.org/accountroot.html#accountroot-flags
+     */
+    pub struct AccountRootFlags: u32 {
+        const PASSWORD_SPENT = 0x0001_0000;
+        const REQUIRE_DEST_TAG = 0x0002_0000;
+        const REQUIRE_AUTH = 0x0004_0000;
+        const DISALLOW_XRP = 0x0008_0000;
+        const DISABLE_MASTER = 0x0010_0000;
+        const NO_FREEZE = 0x0020_0000;
+        const GLOBAL_FREEZE = 0x0040_0000;
+        const DEFAULT_RIPPLE = 0x0080_0000;
+        const DEPOSIT_AUTH = 0x0100_0000;
+    }
+}
+
+bitflags! {
+    /**
+     * `tf` flags of a Payment, the same bits mean something else on other transaction types. [1]
+     * 1: https://xrpl.org/payment.html#payment-flags
+     */
+    pub struct PaymentFlags: u32 {
+        const NO_DIRECT_RIPPLE = 0x0001_0000;
+        const PARTIAL_PAYMENT = 0x0002_0000;
+        const LIMIT_QUALITY = 0x0004_0000;
+        const FULLY_CANONICAL_SIG = 0x8000_0000;
+    }
+}
+
+bitflags! {
+    /// 1: https://xrpl.org/offercreate.html#offercreate-flags
+    pub struct OfferCreateFlags: u32 {
+        const PASSIVE = 0x0001_0000;
+        const IMMEDIATE_OR_CANCEL = 0x0002_0000;
+        const FILL_OR_KILL = 0x0004_0000;
+        const SELL = 0x0008_0000;
+        const FULLY_CANONICAL_SIG = 0x8000_0000;
+    }
+}
+
+bitflags! {
+    /// 1: https://xrpl.org/trustset.html#trustset-flags
+    pub struct TrustSetFlags: u32 {
+        const SET_AUTH = 0x0001_0000;
+        const SET_NO_RIPPLE = 0x0002_0000;
+        const CLEAR_NO_RIPPLE = 0x0004_0000;
+        const SET_FREEZE = 0x0010_0000;
+        const CLEAR_FREEZE = 0x0020_0000;
+        const FULLY_CANONICAL_SIG = 0x8000_0000;
+    }
+}
+
+bitflags! {
+    /// 1: https://xrpl.org/accountset.html#accountset-flags
+    pub struct AccountSetFlags: u32 {
+        const REQUIRE_DEST_TAG = 0x0001_0000;
+        const OPTIONAL_DEST_TAG = 0x0002_0000;
+        const REQUIRE_AUTH = 0x0004_0000;
+        const OPTIONAL_AUTH = 0x0008_0000;
+        const DISALLOW_XRP = 0x0010_0000;
+        const ALLOW_XRP = 0x0020_0000;
+        const FULLY_CANONICAL_SIG = 0x8000_0000;
+    }
+}
+
+impl AccountData {
+    /// Bits rippled defines today, the raw value stays in `Flags`.
+    pub fn flags(&self) -> AccountRootFlags {
+        let raw = self.Flags.as_ref().and_then(|flags| flags.to_u32()).unwrap_or(0);
+        AccountRootFlags::from_bits_truncate(raw)
+    }
+}
+
+impl TransactionInfo {
+    /// Decode with the flag set matching `TransactionType`, e.g. `PaymentFlags::from_bits_truncate`.
+    pub fn raw_flags(&self) -> u32 {
+        self.Flags.unwrap_or(0) as u32
+    }
+}
+
+/**
+ * `sLCF_NoConsensusTime`, the only `close_flags` bit: validators did not agree on a close
+ * time, so `close_time` is just the parent's close time plus one second. [1]
+ * 1: https://xrpl.org/ledger-header.html#close-flags
+ */
+pub const NO_CONSENSUS_TIME: isize = 0x01;
+
+impl NestedLedgerInfo {
+    /// `false` for open ledgers, which have no `close_flags` yet.
+    pub fn no_consensus_time(&self) -> bool {
+        self.close_flags.unwrap_or(0) & NO_CONSENSUS_TIME != 0
+    }
+}
+
+#[test]
+fn account_root_flags_test() {
+    let flags = AccountRootFlags::from_bits_truncate(9_437_184);
+    assert!(flags.contains(AccountRootFlags::DEFAULT_RIPPLE));
+    assert!(flags.contains(AccountRootFlags::DISABLE_MASTER));
+    assert!(!flags.contains(AccountRootFlags::REQUIRE_DEST_TAG));
+    assert_eq!(flags.bits(), 9_437_184);
+}
+
+#[test]
+fn payment_flags_test() {
+    let flags = PaymentFlags::from_bits_truncate(2_147_942_400);
+    assert!(flags.contains(PaymentFlags::FULLY_CANONICAL_SIG));
+    assert!(flags.contains(PaymentFlags::PARTIAL_PAYMENT));
+    assert!(flags.contains(PaymentFlags::NO_DIRECT_RIPPLE | PaymentFlags::LIMIT_QUALITY));
+
+    let flags = OfferCreateFlags::from_bits_truncate(0x8008_0000);
+    assert!(flags.contains(OfferCreateFlags::SELL));
+    assert!(!flags.contains(OfferCreateFlags::PASSIVE));
+}
+
+#[test]
+fn close_flags_test() {
+    let ledger = |close_flags: &str| -> NestedLedgerInfo {
+        serde_json::from_str(&format!(
+            r#"{{
+  "closed": true,
+  {}
+  "ledger_index": "56865245",
+  "parent_hash": "197B5016B33A79CECA4AA704B534D5999A9674FAD9CBDD82309835D7A784A35F",
+  "seqNum": "56865245"
+}}"#,
+            close_flags
+        ))
+        .unwrap()
+    };
+    assert!(ledger(r#""close_flags": 1,"#).no_consensus_time());
+    assert!(!ledger(r#""close_flags": 0,"#).no_consensus_time());
+    assert!(!ledger("").no_consensus_time());
+}
@@ -0,0 +1,76 @@
+use crate::{ServerState, ServerStateDetails, XRPClient};
+use failure::Error;
+
+/// Whether a node is fit to answer queries, with the reason when it is not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Health {
+    pub healthy: bool,
+    pub server_state: ServerState,
+    pub latest_validated_ledger: Option<u64>,
+    pub reason: Option<String>,
+}
+
+impl Health {
+    /// Synced (`full`, `validating` or `proposing`) and holding at least one validated ledger.
+    pub fn from_state(state: &ServerStateDetails) -> Health {
+        let latest_validated_ledger = state.validated_ledger.as_ref().map(|ledger| ledger.seq);
+        let has_ledgers = !state.complete_ledgers.is_empty() && state.complete_ledgers != "empty";
+        let reason = if !state.server_state.is_synced() {
+            Some(format!("The server is {:?}", state.server_state))
+        } else if !has_ledgers {
+            Some("The server has no complete ledgers".to_owned())
+        } else if latest_validated_ledger.is_none() {
+            Some("The server has no validated ledger".to_owned())
+        } else {
+            None
+        };
+        Health {
+            healthy: reason.is_none(),
+            server_state: state.server_state.clone(),
+            latest_validated_ledger,
+            reason,
+        }
+    }
+}
+
+impl XRPClient {
+    /// One `server_state` call, an `Err` only when the node could not be asked at all.
+    pub fn health(&self) -> Result<Health, Error> {
+        self.server_state()
+            .map(|server_state| Health::from_state(&server_state.state))
+    }
+}
+
+#[test]
+fn health_from_state_test() {
+    let state: crate::ServerStateInfo = serde_json::from_str(
+        r#"{
+  "state": {
+    "build_version": "1.5.0",
+    "complete_ledgers": "55414143-55414284",
+    "load_base": 256,
+    "load_factor": 256,
+    "server_state": "proposing",
+    "validated_ledger": {
+      "base_fee": 10,
+      "close_time": 645111330,
+      "hash": "F4AAE088486881FD8EDEF5D35D2D549415E1CA2C6C935CB817A6F67D7A902A90",
+      "reserve_base": 20000000,
+      "reserve_inc": 5000000,
+      "seq": 55414284
+    }
+  },
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        Health::from_state(&state.state),
+        Health {
+            healthy: true,
+            server_state: ServerState::Proposing,
+            latest_validated_ledger: Some(55_414_284),
+            reason: None,
+        }
+    );
+}
@@ -0,0 +1,209 @@
+use crate::{Account, Currency, LedgerIndex, RequestLedgerIndex};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/**
+ * The ways `ledger_entry` can find an object, rippled takes exactly one of them per call. [1]
+ * Objects that are keyed by several fields also take their index as a plain string.
+ * 1: https://xrpl.org/ledger_entry.html
+ */
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntrySelector {
+    Index(String),
+    AccountRoot(Account),
+    Check(String),
+    DepositPreauth(serde_json::Value),
+    Directory(serde_json::Value),
+    Escrow(serde_json::Value),
+    Offer(serde_json::Value),
+    PaymentChannel(String),
+    RippleState(serde_json::Value),
+    Ticket(serde_json::Value),
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LedgerEntryParams {
+    #[serde(flatten)]
+    pub selector: LedgerEntrySelector,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_hash: Option<String>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LedgerEntryParamsBuilder {
+    selectors: Vec<LedgerEntrySelector>,
+    binary: Option<bool>,
+    ledger_hash: Option<String>,
+    ledger_index: Option<RequestLedgerIndex>,
+}
+
+impl LedgerEntryParams {
+    pub fn builder() -> LedgerEntryParamsBuilder {
+        LedgerEntryParamsBuilder::default()
+    }
+}
+
+impl LedgerEntryParamsBuilder {
+    fn select(mut self, selector: LedgerEntrySelector) -> Self {
+        self.selectors.push(selector);
+        self
+    }
+
+    pub fn index(self, index: &str) -> Self {
+        self.select(LedgerEntrySelector::Index(index.into()))
+    }
+
+    pub fn account_root(self, account: &Account) -> Self {
+        self.select(LedgerEntrySelector::AccountRoot(account.clone()))
+    }
+
+    pub fn check(self, index: &str) -> Self {
+        self.select(LedgerEntrySelector::Check(index.into()))
+    }
+
+    pub fn deposit_preauth(self, owner: &Account, authorized: &Account) -> Self {
+        self.select(LedgerEntrySelector::DepositPreauth(
+            json!({ "owner": owner, "authorized": authorized }),
+        ))
+    }
+
+    /// The first page of `owner`'s owner directory.
+    pub fn directory(self, owner: &Account) -> Self {
+        self.select(LedgerEntrySelector::Directory(json!({ "owner": owner })))
+    }
+
+    pub fn escrow(self, owner: &Account, seq: u32) -> Self {
+        self.select(LedgerEntrySelector::Escrow(
+            json!({ "owner": owner, "seq": seq }),
+        ))
+    }
+
+    pub fn offer(self, account: &Account, seq: u32) -> Self {
+        self.select(LedgerEntrySelector::Offer(
+            json!({ "account": account, "seq": seq }),
+        ))
+    }
+
+    pub fn payment_channel(self, channel_id: &str) -> Self {
+        self.select(LedgerEntrySelector::PaymentChannel(channel_id.into()))
+    }
+
+    /// The trust line between two accounts, in either order.
+    pub fn ripple_state(self, account: &Account, peer: &Account, currency: &Currency) -> Self {
+        self.select(LedgerEntrySelector::RippleState(
+            json!({ "accounts": [account, peer], "currency": currency }),
+        ))
+    }
+
+    pub fn ticket(self, account: &Account, ticket_seq: u32) -> Self {
+        self.select(LedgerEntrySelector::Ticket(
+            json!({ "account": account, "ticket_seq": ticket_seq }),
+        ))
+    }
+
+    pub fn binary(mut self, binary: bool) -> Self {
+        self.binary = Some(binary);
+        self
+    }
+
+    pub fn ledger_hash(mut self, ledger_hash: &str) -> Self {
+        self.ledger_hash = Some(ledger_hash.into());
+        self
+    }
+
+    pub fn ledger_index(mut self, ledger_index: RequestLedgerIndex) -> Self {
+        self.ledger_index = Some(ledger_index);
+        self
+    }
+
+    pub fn build(mut self) -> Result<LedgerEntryParams, String> {
+        if self.selectors.len() != 1 {
+            return Err(format!(
+                "ledger_entry takes exactly one object selector, got {}",
+                self.selectors.len()
+            ));
+        }
+        Ok(LedgerEntryParams {
+            selector: self.selectors.remove(0),
+            binary: self.binary,
+            ledger_hash: self.ledger_hash,
+            ledger_index: self.ledger_index,
+        })
+    }
+}
+
+/// `node` is the object as json, `node_binary` its hex encoding when `binary` was asked for.
+#[derive(Deserialize, Debug)]
+pub struct LedgerEntry {
+    pub index: String,
+    pub node: Option<serde_json::Value>,
+    pub node_binary: Option<String>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[test]
+fn ledger_entry_account_root_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let params = LedgerEntryParams::builder()
+        .account_root(&account)
+        .ledger_index(RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        })
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "account_root": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "ledger_index": "validated"
+        })
+    );
+
+    let entry: LedgerEntry = serde_json::from_str(
+        r#"{
+  "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+  "ledger_hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+  "ledger_index": 61966146,
+  "node": {
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Balance": "424021949",
+    "Flags": 0,
+    "LedgerEntryType": "AccountRoot",
+    "OwnerCount": 3,
+    "PreviousTxnID": "0E8A584AA4A5070948A6B5F7BE208A3B4C3A0F3A6C1D72E3F2B3D96BD2AA24D8",
+    "PreviousTxnLgrSeq": 61965653,
+    "Sequence": 4,
+    "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+  },
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert_eq!(entry.node.unwrap()["Account"], "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+}
+
+#[test]
+fn ledger_entry_one_selector_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    assert!(LedgerEntryParams::builder().build().is_err());
+    assert!(LedgerEntryParams::builder()
+        .account_root(&account)
+        .offer(&account, 5)
+        .build()
+        .is_err());
+    let params = LedgerEntryParams::builder().offer(&account, 5).build().unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({ "offer": { "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "seq": 5 } })
+    );
+}
@@ -1,37 +1,124 @@
 #![allow(non_snake_case)]
 
-#[macro_use]
-extern crate throttled_json_rpc;
-
 use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-#[derive(Serialize, Deserialize, Debug)]
+mod address;
+pub mod amount;
+#[cfg(feature = "async")]
+mod async_client;
+mod balance_change;
+mod base58;
+mod batch;
+mod binary;
+mod builder;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod capture;
+mod client;
+mod currency;
+mod error;
+mod failover;
+pub mod fee;
+pub mod flags;
+mod health;
+mod ledger_entry;
+pub mod metrics;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod nft;
+mod paging;
+mod params;
+mod payment;
+mod pool;
+mod reserve;
+pub mod retry;
+mod throttle;
+pub mod time;
+pub mod timeout;
+#[cfg(feature = "tracing")]
+mod trace;
+mod transaction_result;
+mod transaction_type;
+#[cfg(feature = "websocket")]
+pub mod ws;
+
+pub use address::ClassicAddress;
+pub use amount::Drops;
+#[cfg(feature = "async")]
+pub use async_client::AsyncXRPClient;
+pub use balance_change::BalanceChange;
+pub use batch::{Batch, BatchResponse};
+pub use binary::decode_tx_blob;
+pub use builder::XRPClientBuilder;
+pub use client::XRPClient;
+pub use currency::Currency;
+pub use error::{Envelope, Warning, XrpError, XrpResponse};
+pub use failover::{is_endpoint_failure, FailoverClient, FailoverStrategy};
+pub use health::Health;
+pub use ledger_entry::{
+    LedgerEntry, LedgerEntryParams, LedgerEntryParamsBuilder, LedgerEntrySelector,
+};
+#[cfg(feature = "mock")]
+pub use mock::MockTransport;
+pub use params::{AccountInfoParamsBuilder, AccountTxParamsBuilder, LedgerSpecifier};
+pub use payment::PaymentBuilder;
+pub use transaction_result::TransactionResult;
+pub use transaction_type::TransactionType;
+
+/**
+ * Read by the shape of the json rather than by trying each variant: a string or number
+ * is XRP drops, an object an issued amount, and the string "unavailable" `Unavailable`. [1]
+ * 1: https://xrpl.org/basic-data-types.html#specifying-currency-amounts
+ */
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum Balance {
-    XRP(BigDecimal),
+    XRP(Drops),
     Other {
-        currency: String,
+        currency: Currency,
         issuer: String,
         value: BigDecimal,
     },
+    /// `delivered_amount` is the literal string "unavailable" for ledgers before 2014-01-20
+    #[serde(with = "unavailable_balance")]
+    Unavailable,
+}
+
+mod unavailable_balance {
+    pub const UNAVAILABLE: &str = "unavailable";
+
+    pub fn serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(UNAVAILABLE)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 /**
 * Starts with r
 * Length is 25-35 chars in length
+* Base58Check encoded with the XRPL alphabet [2]
+* X-addresses are only built through `Account::from_x_address` [3]
 * 1: https://xrpl.org/basic-data-types.html#addresses
+* 2: https://xrpl.org/base58-encodings.html
+* 3: https://xrpaddress.info/
 */
 pub struct Account(String);
 
+const ACCOUNT_ID_PREFIX: u8 = 0x00;
+const ACCOUNT_ID_LENGTH: usize = 20;
+
 fn account_validate(s: &str) -> Result<String, String> {
     const MIN_LENGTH: usize = 25;
     const MAX_LENGTH: usize = 35;
     if s.len() < MIN_LENGTH {
         return Err(format!("{:?} is shorter than {} chars ", s, MIN_LENGTH));
     }
-    if let Some(first_char) = s.chars().nth(0) {
+    if let Some(first_char) = s.chars().next() {
         if first_char != 'r' {
             return Err(format!("{:?} does not start with r", s));
         }
@@ -39,6 +126,10 @@ fn account_validate(s: &str) -> Result<String, String> {
     if s.len() > MAX_LENGTH {
         return Err(format!("{:?} is longer than {} chars ", s, MAX_LENGTH));
     }
+    let decoded = base58::decode_check(s)?;
+    if decoded.len() != ACCOUNT_ID_LENGTH + 1 || decoded[0] != ACCOUNT_ID_PREFIX {
+        return Err(format!("{:?} does not decode to an account id", s));
+    }
     Ok(s.into())
 }
 
@@ -46,7 +137,7 @@ impl FromStr for Account {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        account_validate(s).map(|account| Account(account))
+        account_validate(s).map(Account)
     }
 }
 
@@ -56,8 +147,10 @@ pub struct AccountInfoParams<'a> {
     pub strict: bool,
 
     #[serde(flatten)]
-    pub ledger_index: LedgerIndex,
+    pub ledger_index: RequestLedgerIndex,
     pub queue: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer_lists: Option<bool>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -68,18 +161,555 @@ pub struct AccountTxParams<'a, 'b> {
     pub ledger_hash: Option<&'b str>,
 
     #[serde(flatten)]
-    pub ledger_index: Option<LedgerIndex>,
+    pub ledger_index: Option<RequestLedgerIndex>,
 
     pub binary: Option<bool>,
     pub forward: Option<bool>,
     pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountLinesParams<'a> {
+    pub account: &'a Account,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer: Option<&'a Account>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountObjectType {
+    Check,
+    Escrow,
+    Offer,
+    PaymentChannel,
+    SignerList,
+    State,
+    Ticket,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountObjectsParams<'a> {
+    pub account: &'a Account,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<AccountObjectType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deletion_blockers_only: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+/**
+ * XRP has no issuer and is sent as just `{"currency": "XRP"}`. [1]
+ * 1: https://xrpl.org/book_offers.html
+ */
+#[derive(Serialize, Debug, Clone)]
+pub struct BookAsset {
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+}
+
+impl BookAsset {
+    pub fn xrp() -> Self {
+        BookAsset {
+            currency: "XRP".into(),
+            issuer: None,
+        }
+    }
+
+    pub fn issued(currency: &str, issuer: &Account) -> Self {
+        BookAsset {
+            currency: currency.into(),
+            issuer: Some(issuer.0.clone()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BookOffersParams<'a> {
+    pub taker_gets: BookAsset,
+    pub taker_pays: BookAsset,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taker: Option<&'a Account>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GatewayBalancesParams<'a> {
+    pub account: &'a Account,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_hotwallet"
+    )]
+    pub hotwallet: Option<Vec<String>>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+/**
+ * rippled takes `hotwallet` as either one address or an array of them. [1]
+ * A single wallet goes out as a plain string, which every server version understands.
+ * 1: https://xrpl.org/gateway_balances.html
+ */
+fn serialize_hotwallet<S>(hotwallet: &Option<Vec<String>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match hotwallet {
+        Some(wallets) if wallets.len() == 1 => serializer.serialize_str(&wallets[0]),
+        Some(wallets) => serde::Serialize::serialize(wallets, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountCurrenciesParams<'a> {
+    pub account: &'a Account,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+/// Which NoRipple setup `noripple_check` compares the trust lines against.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NoRippleRole {
+    Gateway,
+    User,
+}
+
+impl FromStr for NoRippleRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gateway" => Ok(NoRippleRole::Gateway),
+            "user" => Ok(NoRippleRole::User),
+            _ => Err(format!("{:?} is neither \"gateway\" nor \"user\"", s)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct NoRippleCheckParams<'a> {
+    pub account: &'a Account,
+    pub role: NoRippleRole,
+    /// Also return the `TrustSet`/`AccountSet` transactions that would fix the problems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountOffersParams<'a> {
+    pub account: &'a Account,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountChannelsParams<'a> {
+    pub account: &'a Account,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_account: Option<&'a Account>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountNftsParams<'a> {
+    pub account: &'a Account,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct NftOffersParams {
+    pub nft_id: String,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TxHistoryParams {
+    pub start: u64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct FeatureParams {
+    /// An amendment id or short name, all amendments when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<String>,
+    /// Admin only, votes against (or back for) `feature`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vetoed: Option<bool>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct OracleRef {
+    pub account: String,
+    pub oracle_document_id: u32,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GetAggregatePriceParams {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub oracles: Vec<OracleRef>,
+    /// Percentage of outliers to drop from each end for `trimmed_set`, 1 to 25.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim: Option<u32>,
+    /// Seconds, prices older than the newest one by more than this are left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_threshold: Option<u32>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+/**
+ * An AMM pool, named either by its account or by the two assets it holds. [1]
+ * `new` refuses params that name it both ways or not at all.
+ * 1: https://xrpl.org/amm_info.html
+ */
+#[derive(Serialize, Debug, Clone)]
+pub struct AmmInfoParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amm_account: Option<&'a Account>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<BookAsset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset2: Option<BookAsset>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+impl<'a> AmmInfoParams<'a> {
+    pub fn new(
+        amm_account: Option<&'a Account>,
+        assets: Option<(BookAsset, BookAsset)>,
+        ledger_index: Option<RequestLedgerIndex>,
+    ) -> Result<Self, String> {
+        let (asset, asset2) = match (amm_account, assets) {
+            (Some(_), Some(_)) => {
+                return Err("amm_info takes an amm_account or an asset pair, not both".into())
+            }
+            (None, None) => return Err("amm_info needs an amm_account or an asset pair".into()),
+            (_, assets) => match assets {
+                Some((asset, asset2)) => (Some(asset), Some(asset2)),
+                None => (None, None),
+            },
+        };
+        Ok(AmmInfoParams {
+            amm_account,
+            asset,
+            asset2,
+            ledger_index,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ManifestParams {
+    pub public_key: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DepositAuthorizedParams<'a> {
+    pub source_account: &'a Account,
+    pub destination_account: &'a Account,
+    /// Hex ids of credentials the sender wants to present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<Vec<String>>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RipplePathFindParams<'a> {
+    pub source_account: &'a Account,
+    pub destination_account: &'a Account,
+    pub destination_amount: Balance,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_max: Option<Balance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_currencies: Option<Vec<BookAsset>>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct TxParams {
+    pub transaction: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_ledger: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ledger: Option<u64>,
+}
+
+/**
+ * A key rippled signs with: a `secret`, `seed`, `seed_hex` or `passphrase`. It travels to
+ * the server as is, so only send one to a rippled you run yourself, over localhost or TLS,
+ * never to a public node. The params holding one check in `new` that exactly one key is
+ * given and keep it private, and `Debug` prints it as `<redacted>`.
+ */
+#[derive(Serialize, Clone)]
+#[serde(transparent)]
+struct Secret(String);
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+/**
+ * Signs a claim against a payment channel with one `Secret`. [1]
+ * 1: https://xrpl.org/channel_authorize.html
+ */
+#[derive(Serialize, Clone, Debug)]
+pub struct ChannelAuthorizeParams {
+    pub channel_id: String,
+    pub amount: Drops,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed_hex: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    passphrase: Option<Secret>,
+}
+
+impl ChannelAuthorizeParams {
+    pub fn new(
+        channel_id: String,
+        amount: Drops,
+        secret: Option<String>,
+        seed: Option<String>,
+        seed_hex: Option<String>,
+        passphrase: Option<String>,
+    ) -> Result<Self, String> {
+        let keys = [&secret, &seed, &seed_hex, &passphrase];
+        if keys.iter().filter(|key| key.is_some()).count() != 1 {
+            return Err("Give exactly one of a secret, seed, seed_hex or passphrase".into());
+        }
+        Ok(ChannelAuthorizeParams {
+            channel_id,
+            amount,
+            key_type: None,
+            secret: secret.map(Secret),
+            seed: seed.map(Secret),
+            seed_hex: seed_hex.map(Secret),
+            passphrase: passphrase.map(Secret),
+        })
+    }
+}
+
+/// Exactly one of the ways to give rippled a key has to be used.
+fn one_secret(secret: &Option<String>, seed: &Option<String>) -> Result<(), String> {
+    match (secret, seed) {
+        (Some(_), Some(_)) => Err("Give either a secret or a seed, not both".into()),
+        (None, None) => Err("Signing needs a secret or a seed".into()),
+        _ => Ok(()),
+    }
+}
+
+/**
+ * Has rippled sign `tx_json` with one `Secret`, filling in `Fee`, `Sequence` and the
+ * like unless `offline`. [1]
+ * 1: https://xrpl.org/sign.html
+ */
+#[derive(Serialize, Clone, Debug)]
+pub struct SignParams {
+    pub tx_json: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offline: Option<bool>,
+}
+
+impl SignParams {
+    pub fn new(
+        tx_json: serde_json::Value,
+        secret: Option<String>,
+        seed: Option<String>,
+    ) -> Result<Self, String> {
+        one_secret(&secret, &seed)?;
+        Ok(SignParams {
+            tx_json,
+            secret: secret.map(Secret),
+            seed: seed.map(Secret),
+            key_type: None,
+            offline: None,
+        })
+    }
+}
+
+/**
+ * Adds `account`'s signature to a multi-signed `tx_json`, signing with one `Secret`. [1]
+ * 1: https://xrpl.org/sign_for.html
+ */
+#[derive(Serialize, Clone, Debug)]
+pub struct SignForParams<'a> {
+    pub account: &'a Account,
+    pub tx_json: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+}
+
+impl<'a> SignForParams<'a> {
+    pub fn new(
+        account: &'a Account,
+        tx_json: serde_json::Value,
+        secret: Option<String>,
+        seed: Option<String>,
+    ) -> Result<Self, String> {
+        one_secret(&secret, &seed)?;
+        Ok(SignForParams {
+            account,
+            tx_json,
+            secret: secret.map(Secret),
+            seed: seed.map(Secret),
+            key_type: None,
+        })
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ChannelVerifyParams {
+    pub channel_id: String,
+    pub amount: Drops,
+    /// Hex or base58 public key of the channel.
+    pub public_key: String,
+    pub signature: String,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct TransactionEntryParams {
+    pub tx_hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_hash: Option<String>,
+
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SubmitParams {
+    pub tx_blob: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_hard: Option<bool>,
+}
+
+/**
+ * A transaction signed by several keys, built with `new`, which
+ * refuses a `tx_json` without signatures before anything is sent. [1]
+ * 1: https://xrpl.org/submit_multisigned.html
+ */
+#[derive(Serialize, Clone, Debug)]
+pub struct SubmitMultisignedParams {
+    pub tx_json: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_hard: Option<bool>,
+}
+
+impl SubmitMultisignedParams {
+    pub fn new(tx_json: serde_json::Value, fail_hard: Option<bool>) -> Result<Self, String> {
+        match tx_json.get("Signers").and_then(serde_json::Value::as_array) {
+            Some(signers) if !signers.is_empty() => {
+                Ok(SubmitMultisignedParams { tx_json, fail_hard })
+            }
+            Some(_) => Err("The Signers of a multi-signed transaction cannot be empty".into()),
+            None => Err("A multi-signed transaction needs a Signers array".into()),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct LedgerDataParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_hash: Option<String>,
+    #[serde(flatten)]
+    pub ledger_index: Option<RequestLedgerIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<serde_json::Value>,
+    /// e.g. `account`, `offer` or `state`, to list only one kind of object.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<String>,
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct LedgerInfoParams {
     pub ledger_hash: Option<String>,
     #[serde(flatten)]
-    pub ledger_index: Option<LedgerIndex>,
+    pub ledger_index: Option<RequestLedgerIndex>,
     pub full: Option<bool>,
     pub accounts: Option<bool>,
     pub transactions: Option<bool>,
@@ -89,32 +719,128 @@ pub struct LedgerInfoParams {
     pub queue: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+/**
+ * Ledger object types as listed in [1]. rippled keeps adding new ones, so anything
+ * not listed here lands in `Unknown` instead of failing the whole response.
+ * 1: https://xrpl.org/ledger-object-types.html
+ */
+#[derive(Debug, Clone, PartialEq)]
 pub enum LedgerEntryType {
     AccountRoot, // WHY DOES THIS EVEN EXIST???
+    Amendments,
+    AMM,
+    Bridge,
+    Check,
+    Credential,
+    DepositPreauth,
+    DID,
+    DirectoryNode,
+    Escrow,
+    FeeSettings,
+    LedgerHashes,
+    MPToken,
+    MPTokenIssuance,
+    NegativeUNL,
+    NFTokenOffer,
+    NFTokenPage,
+    Offer,
+    Oracle,
+    PayChannel,
+    PermissionedDomain,
+    RippleState,
+    SignerList,
+    Ticket,
+    XChainOwnedClaimID,
+    XChainOwnedCreateAccountClaimID,
+    Unknown(String),
+}
+
+impl<'de> serde::Deserialize<'de> for LedgerEntryType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let entry_type = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(match entry_type.as_str() {
+            "AccountRoot" => LedgerEntryType::AccountRoot,
+            "Amendments" => LedgerEntryType::Amendments,
+            "AMM" => LedgerEntryType::AMM,
+            "Bridge" => LedgerEntryType::Bridge,
+            "Check" => LedgerEntryType::Check,
+            "Credential" => LedgerEntryType::Credential,
+            "DepositPreauth" => LedgerEntryType::DepositPreauth,
+            "DID" => LedgerEntryType::DID,
+            "DirectoryNode" => LedgerEntryType::DirectoryNode,
+            "Escrow" => LedgerEntryType::Escrow,
+            "FeeSettings" => LedgerEntryType::FeeSettings,
+            "LedgerHashes" => LedgerEntryType::LedgerHashes,
+            "MPToken" => LedgerEntryType::MPToken,
+            "MPTokenIssuance" => LedgerEntryType::MPTokenIssuance,
+            "NegativeUNL" => LedgerEntryType::NegativeUNL,
+            "NFTokenOffer" => LedgerEntryType::NFTokenOffer,
+            "NFTokenPage" => LedgerEntryType::NFTokenPage,
+            "Offer" => LedgerEntryType::Offer,
+            "Oracle" => LedgerEntryType::Oracle,
+            "PayChannel" => LedgerEntryType::PayChannel,
+            "PermissionedDomain" => LedgerEntryType::PermissionedDomain,
+            "RippleState" => LedgerEntryType::RippleState,
+            "SignerList" => LedgerEntryType::SignerList,
+            "Ticket" => LedgerEntryType::Ticket,
+            "XChainOwnedClaimID" => LedgerEntryType::XChainOwnedClaimID,
+            "XChainOwnedCreateAccountClaimID" => LedgerEntryType::XChainOwnedCreateAccountClaimID,
+            _ => LedgerEntryType::Unknown(entry_type),
+        })
+    }
 }
 
 #[derive(Deserialize, Debug)]
 pub struct AccountData {
     pub Account: String,
-    pub Balance: BigDecimal,
+    pub Balance: Drops,
     pub Flags: Option<BigDecimal>,
     pub LedgerEntryType: LedgerEntryType,
-    pub OwnerCount: BigDecimal,
+    /// UInt32 on the ledger, anything larger or fractional fails to deserialize.
+    pub OwnerCount: u32,
     pub PreviousTxnID: String,
-    pub PreviousTxnLgrSeq: BigDecimal,
-    pub Sequence: BigDecimal,
+    pub PreviousTxnLgrSeq: u64,
+    pub Sequence: u32,
     pub index: String,
+    /// Only with `signer_lists: true`, and then empty unless multi-signing is set up.
+    pub signer_lists: Option<Vec<SignerList>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SignerListEntry {
+    pub Account: String,
+    pub SignerWeight: u16,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SignerListEntryWrapper {
+    pub SignerEntry: SignerListEntry,
+}
+
+/**
+ * Who may multi-sign for an account, and how much weight it takes. [1]
+ * 1: https://xrpl.org/signerlist.html
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct SignerList {
+    pub Flags: u32,
+    pub SignerEntries: Vec<SignerListEntryWrapper>,
+    pub SignerListID: u32,
+    pub SignerQuorum: u32,
+    pub index: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct QueuedTransaction {
-    pub LastLedgerSequence: Option<BigDecimal>,
+    pub LastLedgerSequence: Option<u64>,
     pub auth_change: bool,
-    pub fee: BigDecimal,
+    pub fee: Drops,
     pub fee_level: BigDecimal,
-    pub max_spend_drops: BigDecimal,
-    pub seq: BigDecimal,
+    pub max_spend_drops: Drops,
+    pub seq: u64,
 }
 
 #[derive(Deserialize, Debug)]
@@ -124,10 +850,57 @@ pub struct AccountTransaction {
     pub validated: bool,
 }
 
+/**
+ * With `binary: true` the transaction and its metadata come as hex encoded blobs. [1]
+ * 1: https://xrpl.org/account_tx.html#response-format
+ */
+#[derive(Deserialize, Debug)]
+pub struct BinaryAccountTransaction {
+    pub ledger_index: u64,
+    pub meta: String,
+    pub tx_blob: String,
+    pub validated: bool,
+}
+
+/// One `account_tx` entry, `Binary` when the request set `binary: true`.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum AccountTxEntry {
+    Binary(BinaryAccountTransaction),
+    Json(AccountTransaction),
+}
+
+impl AccountTxEntry {
+    pub fn as_json(&self) -> Option<&AccountTransaction> {
+        match self {
+            AccountTxEntry::Json(transaction) => Some(transaction),
+            AccountTxEntry::Binary(_) => None,
+        }
+    }
+
+    pub fn as_binary(&self) -> Option<&BinaryAccountTransaction> {
+        match self {
+            AccountTxEntry::Binary(transaction) => Some(transaction),
+            AccountTxEntry::Json(_) => None,
+        }
+    }
+
+    /// The `TransactionType`, decoded from `tx_blob` for binary entries.
+    pub fn transaction_type(&self) -> Option<TransactionType> {
+        match self {
+            AccountTxEntry::Json(transaction) => transaction.tx.TransactionType.clone(),
+            AccountTxEntry::Binary(transaction) => decode_tx_blob(&transaction.tx_blob)
+                .ok()
+                .map(|tx| tx.TransactionType),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct AccountTransactionTx {
     #[serde(flatten)]
     pub ledger_index: LedgerIndex,
+    pub TransactionType: Option<TransactionType>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -148,13 +921,88 @@ pub enum LedgerIndex {
     StrValue { ledger_index: String },
 }
 
-#[derive(Deserialize, Debug)]
-pub struct AccountInfo {
-    pub account_data: Option<AccountData>,
-    pub queue_data: Option<LaziedQueueData>,
-    pub status: String,
-    pub validated: Option<bool>,
-
+/**
+ * The `ledger_index` of a request, a sequence number or a shortcut like `"validated"`. [1]
+ * Replies use `LedgerIndex`, whose `Current` variant has a key requests do not take;
+ * convert one with `from` to ask for the same ledger again.
+ * 1: https://xrpl.org/basic-data-types.html#specifying-ledgers
+ */
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum RequestLedgerIndex {
+    Number { ledger_index: serde_json::Number },
+    /// Checked like `from_str` when the request is serialized, so a typo fails the call.
+    StrValue {
+        #[serde(serialize_with = "serialize_ledger_keyword")]
+        ledger_index: String,
+    },
+}
+
+/// Refuses anything `RequestLedgerIndex::from_str` refuses, before rippled sees it.
+fn serialize_ledger_keyword<S>(ledger_index: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::Error as _;
+    ledger_index
+        .parse::<RequestLedgerIndex>()
+        .map_err(S::Error::custom)?;
+    serializer.serialize_str(ledger_index)
+}
+
+impl From<&LedgerIndex> for RequestLedgerIndex {
+    fn from(ledger_index: &LedgerIndex) -> Self {
+        match ledger_index {
+            LedgerIndex::Number { ledger_index } => RequestLedgerIndex::Number {
+                ledger_index: ledger_index.clone(),
+            },
+            LedgerIndex::StrValue { ledger_index } => RequestLedgerIndex::StrValue {
+                ledger_index: ledger_index.clone(),
+            },
+            LedgerIndex::Current {
+                ledger_current_index,
+            } => match ledger_index.as_u64() {
+                Some(index) => RequestLedgerIndex::Number {
+                    ledger_index: index.into(),
+                },
+                None => RequestLedgerIndex::StrValue {
+                    ledger_index: ledger_current_index.to_string(),
+                },
+            },
+        }
+    }
+}
+
+impl From<LedgerIndex> for RequestLedgerIndex {
+    fn from(ledger_index: LedgerIndex) -> Self {
+        RequestLedgerIndex::from(&ledger_index)
+    }
+}
+
+impl LedgerIndex {
+    /// The sequence number, `None` for shortcuts like `"validated"` and non integer values.
+    pub fn as_u64(&self) -> Option<u64> {
+        use num_traits::ToPrimitive;
+        match self {
+            LedgerIndex::Current {
+                ledger_current_index,
+            } if ledger_current_index.with_scale(0) == *ledger_current_index => {
+                ledger_current_index.to_u64()
+            }
+            LedgerIndex::Current { .. } => None,
+            LedgerIndex::Number { ledger_index } => ledger_index.as_u64(),
+            LedgerIndex::StrValue { ledger_index } => ledger_index.parse().ok(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountInfo {
+    pub account_data: Option<AccountData>,
+    pub queue_data: Option<LaziedQueueData>,
+    pub status: String,
+    pub validated: Option<bool>,
+
     #[serde(flatten)]
     pub ledger_index: LedgerIndex,
 }
@@ -165,7 +1013,395 @@ pub struct AccountTx {
     pub ledger_index_min: i64,
     pub ledger_index_max: i64,
     pub limit: i64,
-    pub transactions: Vec<AccountTransaction>,
+    pub marker: Option<serde_json::Value>,
+    pub transactions: Vec<AccountTxEntry>,
+}
+
+/**
+ * The flags are left out of the response when they are not set. [1]
+ * 1: https://xrpl.org/account_lines.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct TrustLine {
+    pub account: String,
+    pub balance: BigDecimal,
+    pub currency: String,
+    pub limit: BigDecimal,
+    pub limit_peer: BigDecimal,
+    pub quality_in: BigDecimal,
+    pub quality_out: BigDecimal,
+    #[serde(default)]
+    pub no_ripple: bool,
+    #[serde(default)]
+    pub no_ripple_peer: bool,
+    #[serde(default)]
+    pub authorized: bool,
+    #[serde(default)]
+    pub peer_authorized: bool,
+    #[serde(default)]
+    pub freeze: bool,
+    #[serde(default)]
+    pub freeze_peer: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountLines {
+    pub account: Account,
+    pub lines: Vec<TrustLine>,
+    pub marker: Option<serde_json::Value>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountObjects {
+    pub account: Account,
+    pub account_objects: Vec<serde_json::Value>,
+    pub limit: Option<u64>,
+    pub marker: Option<serde_json::Value>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+/**
+ * One page of the objects in a ledger, raw like `account_objects`. [1]
+ * 1: https://xrpl.org/ledger_data.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct LedgerData {
+    pub ledger_hash: String,
+    pub marker: Option<serde_json::Value>,
+    pub state: Vec<serde_json::Value>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Offer {
+    pub Account: String,
+    pub BookDirectory: String,
+    pub BookNode: Option<String>,
+    pub Flags: Option<isize>,
+    pub OwnerNode: Option<String>,
+    pub PreviousTxnID: Option<String>,
+    pub PreviousTxnLgrSeq: Option<u64>,
+    pub Sequence: u64,
+    pub TakerGets: Balance,
+    pub TakerPays: Balance,
+    pub index: Option<String>,
+    pub owner_funds: Option<BigDecimal>,
+    pub quality: Option<BigDecimal>,
+    pub taker_gets_funded: Option<Balance>,
+    pub taker_pays_funded: Option<Balance>,
+}
+
+/**
+ * An offer as `account_offers` lists it, in lower case unlike the ledger object. [1]
+ * 1: https://xrpl.org/account_offers.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct AccountOffer {
+    pub expiration: Option<u32>,
+    pub flags: u32,
+    pub quality: BigDecimal,
+    pub seq: u64,
+    pub taker_gets: Balance,
+    pub taker_pays: Balance,
+}
+
+/**
+ * What the taker pays per unit they get, with XRP counted in XRP rather than drops.
+ * rippled's own `quality` divides the raw amounts, so it is off by 10^6 when one side is XRP.
+ */
+fn quality_ratio(taker_pays: &Balance, taker_gets: &Balance) -> Option<BigDecimal> {
+    let gets = taker_gets.value()?;
+    if gets == BigDecimal::from(0) {
+        return None;
+    }
+    Some(taker_pays.value()? / gets)
+}
+
+impl Offer {
+    pub fn quality_ratio(&self) -> Option<BigDecimal> {
+        quality_ratio(&self.TakerPays, &self.TakerGets)
+    }
+}
+
+impl AccountOffer {
+    pub fn quality_ratio(&self) -> Option<BigDecimal> {
+        quality_ratio(&self.taker_pays, &self.taker_gets)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountOffers {
+    pub account: Account,
+    pub marker: Option<serde_json::Value>,
+    pub offers: Vec<AccountOffer>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+/**
+ * A payment channel owned by the queried account, `amount` is what was set aside
+ * and `balance` what has been paid out of it so far. [1]
+ * 1: https://xrpl.org/account_channels.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct Channel {
+    pub account: String,
+    pub amount: Drops,
+    pub balance: Drops,
+    pub cancel_after: Option<u32>,
+    pub channel_id: String,
+    pub destination_account: String,
+    pub destination_tag: Option<u32>,
+    pub expiration: Option<u32>,
+    pub public_key: Option<String>,
+    pub public_key_hex: Option<String>,
+    pub settle_delay: u32,
+    pub source_tag: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountChannels {
+    pub account: Account,
+    pub channels: Vec<Channel>,
+    pub limit: Option<u64>,
+    pub marker: Option<serde_json::Value>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+/**
+ * An XLS-20 NFT held by the account. [1]
+ * 1: https://xrpl.org/account_nfts.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct NftObject {
+    pub Flags: u32,
+    pub Issuer: String,
+    pub NFTokenID: String,
+    pub NFTokenTaxon: u32,
+    pub TransferFee: Option<u32>,
+    /// Hex encoded, see `decode_uri`.
+    pub URI: Option<String>,
+    pub nft_serial: u32,
+}
+
+impl NftObject {
+    /// `URI` as text, `None` when it is missing or not hex encoded UTF-8.
+    pub fn decode_uri(&self) -> Option<String> {
+        hex_utf8(&self.URI)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountNfts {
+    pub account: Account,
+    pub account_nfts: Vec<NftObject>,
+    pub limit: Option<u64>,
+    pub marker: Option<serde_json::Value>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NftOffer {
+    pub amount: Balance,
+    pub destination: Option<String>,
+    pub expiration: Option<u32>,
+    pub flags: u32,
+    pub nft_offer_index: String,
+    pub owner: String,
+}
+
+/**
+ * The buy or sell offers for one NFT, see `nft_buy_offers_or_empty` for tokens without any. [1]
+ * 1: https://xrpl.org/nft_buy_offers.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct NftOffers {
+    pub nft_id: String,
+    pub offers: Vec<NftOffer>,
+    pub limit: Option<u64>,
+    pub marker: Option<serde_json::Value>,
+}
+
+/**
+ * 20 recent transactions from any ledger, skipping the first `start`. [1]
+ * Deprecated, and nodes built without it answer `notImpl`, which `into_result`
+ * turns into an `XrpError` like any other error reply.
+ * 1: https://xrpl.org/tx_history.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct TxHistory {
+    pub index: u64,
+    pub txs: Vec<TransactionInfo>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Amendment {
+    pub enabled: bool,
+    pub name: String,
+    pub supported: bool,
+    pub vetoed: bool,
+}
+
+/**
+ * Amendments keyed by id. [1] All of them come inside `features`, while a single
+ * requested one comes as a key of the result itself; both end up in `features`.
+ * 1: https://xrpl.org/feature.html
+ */
+#[derive(Debug)]
+pub struct FeatureInfo {
+    pub features: std::collections::HashMap<String, Amendment>,
+}
+
+impl<'de> serde::Deserialize<'de> for FeatureInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let mut result =
+            <serde_json::Map<String, serde_json::Value> as serde::Deserialize>::deserialize(
+                deserializer,
+            )?;
+        let features = match result.remove("features") {
+            Some(features) => serde_json::from_value(features).map_err(D::Error::custom)?,
+            None => result
+                .into_iter()
+                .filter(|(_, value)| value.is_object())
+                .map(|(id, amendment)| {
+                    serde_json::from_value(amendment).map(|amendment| (id, amendment))
+                })
+                .collect::<Result<_, _>>()
+                .map_err(D::Error::custom)?,
+        };
+        Ok(FeatureInfo { features })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PriceStatistics {
+    pub mean: BigDecimal,
+    pub size: u32,
+    pub standard_deviation: BigDecimal,
+}
+
+/**
+ * Statistics over the prices the oracles last reported for one asset pair. [1]
+ * `trimmed_set` is only there when `trim` was given.
+ * 1: https://xrpl.org/get_aggregate_price.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct AggregatePrice {
+    pub entire_set: PriceStatistics,
+    pub median: BigDecimal,
+    pub time: u32,
+    pub trimmed_set: Option<PriceStatistics>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AuthAccount {
+    pub account: String,
+}
+
+/// Whoever holds the slot trades against the pool at `discounted_fee` until `expiration`.
+#[derive(Deserialize, Debug)]
+pub struct AuctionSlot {
+    pub account: String,
+    pub auth_accounts: Option<Vec<AuthAccount>>,
+    pub discounted_fee: u32,
+    pub expiration: String,
+    pub price: Balance,
+    pub time_interval: u32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VoteSlot {
+    pub account: String,
+    pub trading_fee: u32,
+    pub vote_weight: u32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Amm {
+    pub account: String,
+    pub amount: Balance,
+    pub amount2: Balance,
+    pub asset_frozen: Option<bool>,
+    pub asset2_frozen: Option<bool>,
+    pub auction_slot: Option<AuctionSlot>,
+    pub lp_token: Balance,
+    /// In units of 1/100,000, so 500 is 0.5%.
+    pub trading_fee: u32,
+    pub vote_slots: Option<Vec<VoteSlot>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AmmInfo {
+    pub amm: Amm,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ManifestDetails {
+    pub domain: Option<String>,
+    pub ephemeral_key: String,
+    pub master_key: String,
+    pub seq: u32,
+}
+
+/**
+ * The latest manifest of a validator's master key. [1]
+ * A key the server has no manifest for only comes back as `requested`.
+ * 1: https://xrpl.org/manifest.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct Manifest {
+    pub details: Option<ManifestDetails>,
+    pub manifest: Option<String>,
+    pub requested: String,
+}
+
+/// `ping` answers with an empty result, or just `status`, so nothing is kept.
+#[derive(Deserialize, Debug)]
+pub struct Ping {}
+
+#[derive(Deserialize, Debug)]
+pub struct Random {
+    /// 256 random bits, hex encoded.
+    pub random: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BookOffers {
+    pub offers: Vec<Offer>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
 }
 
 /**
@@ -184,26 +1420,47 @@ pub struct LaziedQueueData {
 
 #[derive(Deserialize, Debug)]
 pub struct PathInfo {
-    pub currency: Option<String>,
+    pub account: Option<String>,
+    pub currency: Option<Currency>,
     pub issuer: Option<String>,
     #[serde(rename = "type")]
     pub currency_type: BigDecimal,
     pub type_hex: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct PathAlternative {
+    pub destination_amount: Option<Balance>,
+    pub paths_computed: Vec<Vec<PathInfo>>,
+    pub source_amount: Balance,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RipplePathFind {
+    pub alternatives: Vec<PathAlternative>,
+    pub destination_account: String,
+    pub destination_currencies: Option<Vec<String>>,
+    pub full_reply: Option<bool>,
+    pub source_account: Option<String>,
+    pub validated: Option<bool>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct FinalFieldInfo {
     pub Account: Option<String>,
     pub Balance: Option<Balance>,
     pub Flags: Option<isize>,
-    pub OwnerCount: Option<BigDecimal>,
-    pub Sequence: Option<BigDecimal>,
+    /// The two sides of a `RippleState`, each limit's `issuer` being the account that set it.
+    pub HighLimit: Option<Balance>,
+    pub LowLimit: Option<Balance>,
+    pub OwnerCount: Option<u64>,
+    pub Sequence: Option<u64>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct PreviousFieldInfo {
     pub Balance: Option<Balance>,
-    pub Sequence: Option<BigDecimal>,
+    pub Sequence: Option<u64>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -217,84 +1474,2612 @@ pub struct ModifiedNodeInfo {
 }
 
 #[derive(Deserialize, Debug)]
-pub struct AffectedNodeInfo {
-    pub ModifiedNode: Option<ModifiedNodeInfo>,
+pub struct CreatedNodeInfo {
+    pub LedgerEntryType: String,
+    pub LedgerIndex: String,
+    pub NewFields: Option<FinalFieldInfo>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeletedNodeInfo {
+    pub FinalFields: Option<FinalFieldInfo>,
+    pub PreviousFields: Option<PreviousFieldInfo>,
+    pub LedgerEntryType: String,
+    pub LedgerIndex: String,
+}
+
+/**
+ * Every entry of `AffectedNodes` is an object with exactly one of these keys. [1]
+ * 1: https://xrpl.org/transaction-metadata.html#affectednodes
+ */
+#[derive(Deserialize, Debug)]
+pub enum AffectedNodeInfo {
+    CreatedNode(CreatedNodeInfo),
+    ModifiedNode(ModifiedNodeInfo),
+    DeletedNode(DeletedNodeInfo),
+}
+
+impl AffectedNodeInfo {
+    pub fn ledger_entry_type(&self) -> &str {
+        match self {
+            AffectedNodeInfo::CreatedNode(node) => &node.LedgerEntryType,
+            AffectedNodeInfo::ModifiedNode(node) => &node.LedgerEntryType,
+            AffectedNodeInfo::DeletedNode(node) => &node.LedgerEntryType,
+        }
+    }
+
+    pub fn ledger_index(&self) -> &str {
+        match self {
+            AffectedNodeInfo::CreatedNode(node) => &node.LedgerIndex,
+            AffectedNodeInfo::ModifiedNode(node) => &node.LedgerIndex,
+            AffectedNodeInfo::DeletedNode(node) => &node.LedgerIndex,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
 pub struct MetaTxInfo {
     pub AffectedNodes: Vec<AffectedNodeInfo>,
     pub TransactionIndex: BigDecimal,
-    pub TransactionResult: String,
+    pub TransactionResult: TransactionResult,
+    /**
+     * What a (partial) payment actually delivered, `Amount` is only an upper bound. [1]
+     * 1: https://xrpl.org/partial-payments.html
+     */
+    pub delivered_amount: Option<Balance>,
+    pub DeliveredAmount: Option<Balance>,
+}
+
+impl MetaTxInfo {
+    /**
+     * `delivered_amount`, or the older `DeliveredAmount` when that is all there is.
+     * `None` when neither is there or the amount is "unavailable". [1]
+     * 1: https://xrpl.org/transaction-metadata.html#delivered_amount
+     */
+    pub fn delivered(&self) -> Option<Balance> {
+        self.delivered_amount
+            .as_ref()
+            .or(self.DeliveredAmount.as_ref())
+            .filter(|amount| !matches!(amount, Balance::Unavailable))
+            .cloned()
+    }
+
+    /**
+     * `AffectedNodes` by `LedgerEntryType`, then `LedgerIndex`. rippled does not promise
+     * an order, so reports and snapshots built from these come out the same every time.
+     */
+    pub fn sorted_nodes(&self) -> Vec<&AffectedNodeInfo> {
+        let mut nodes: Vec<_> = self.AffectedNodes.iter().collect();
+        nodes.sort_by(|a, b| {
+            (a.ledger_entry_type(), a.ledger_index())
+                .cmp(&(b.ledger_entry_type(), b.ledger_index()))
+        });
+        nodes
+    }
+}
+
+/**
+ * Arbitrary data attached to a transaction, every field hex encoded. [1]
+ * 1: https://xrpl.org/transaction-common-fields.html#memos-field
+ */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Memo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub MemoData: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub MemoFormat: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub MemoType: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MemoWrapper {
+    pub Memo: Memo,
+}
+
+fn hex_utf8(field: &Option<String>) -> Option<String> {
+    let bytes = hex::decode(field.as_ref()?).ok()?;
+    String::from_utf8(bytes).ok()
+}
+
+impl Memo {
+    /// `None` when missing or not valid hex encoded UTF-8.
+    pub fn data_utf8(&self) -> Option<String> {
+        hex_utf8(&self.MemoData)
+    }
+
+    pub fn format_utf8(&self) -> Option<String> {
+        hex_utf8(&self.MemoFormat)
+    }
+
+    pub fn type_utf8(&self) -> Option<String> {
+        hex_utf8(&self.MemoType)
+    }
+}
+
+/// One signature of a multi-signed transaction.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Signer {
+    pub Account: String,
+    pub SigningPubKey: String,
+    pub TxnSignature: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SignerEntry {
+    pub Signer: Signer,
+}
+
+/**
+ * Pseudo-transactions (`EnableAmendment`, `SetFee`, `UNLModify`) are made by the network
+ * rather than an account, so they may come without `Account`, `Fee`, `Sequence` or a
+ * signature; `Fee` and `Sequence` are then `None`. [1]
+ * 1: https://xrpl.org/pseudo-transaction-types.html
+ */
 #[derive(Deserialize, Debug)]
 pub struct TransactionInfo {
-    pub Account: String,
+    pub Account: Option<String>,
+    /// The amendment an `EnableAmendment` is about.
+    pub Amendment: Option<String>,
     pub Amount: Option<Balance>,
     pub Destination: Option<String>,
-    pub Fee: BigDecimal,
+    pub DestinationTag: Option<u32>,
+    pub Expiration: Option<u32>,
+    pub Fee: Option<Drops>,
     pub Flags: Option<isize>,
+    pub LastLedgerSequence: Option<u64>,
+    /// The flag ledger a pseudo-transaction applies to.
+    pub LedgerSequence: Option<u32>,
+    /// The trust line a `TrustSet` creates or changes.
+    pub LimitAmount: Option<Balance>,
+    pub Memos: Option<Vec<MemoWrapper>>,
+    /// The offer an `OfferCancel` removes, or an `OfferCreate` replaces.
+    pub OfferSequence: Option<u64>,
     pub Paths: Option<Vec<Vec<PathInfo>>>,
+    pub QualityIn: Option<u32>,
+    pub QualityOut: Option<u32>,
+    /**
+     * The new fee and reserve settings of a `SetFee`, `BaseFee` being hex encoded drops. [1]
+     * 1: https://xrpl.org/setfee.html
+     */
+    pub BaseFee: Option<String>,
+    pub ReferenceFeeUnits: Option<u32>,
+    pub ReserveBase: Option<u32>,
+    pub ReserveIncrement: Option<u32>,
     pub SendMax: Option<Balance>,
-    pub Sequence: BigDecimal,
-    pub SigningPubKey: String,
-    pub TransactionType: String,
+    pub Sequence: Option<u64>,
+    /**
+     * Multi-signed transactions leave these empty and carry `Signers` instead. [1]
+     * 1: https://xrpl.org/multi-signing.html
+     */
+    pub Signers: Option<Vec<SignerEntry>>,
+    pub SigningPubKey: Option<String>,
+    /**
+     * What an `OfferCreate` gives and wants in return. [1]
+     * 1: https://xrpl.org/offercreate.html
+     */
+    pub TakerGets: Option<Balance>,
+    pub TakerPays: Option<Balance>,
+    pub TickSize: Option<u8>,
+    pub TransactionType: TransactionType,
     pub TxnSignature: Option<String>,
+    pub date: Option<BigDecimal>,
     pub hash: String,
-    pub metaData: MetaTxInfo,
+    /**
+     * `tx` only reports these once the transaction made it into a closed ledger. [1]
+     * 1: https://xrpl.org/tx.html
+     */
+    pub ledger_index: Option<BigDecimal>,
+    #[serde(alias = "meta")]
+    pub metaData: Option<MetaTxInfo>,
     pub validated: Option<bool>, //option of a bool???
 }
 
+/**
+ * A transaction as recorded in one particular ledger, unlike `tx` this never
+ * looks at other ledgers. [1]
+ * 1: https://xrpl.org/transaction_entry.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct TransactionEntry {
+    pub ledger_hash: Option<String>,
+    pub metadata: MetaTxInfo,
+    pub tx_json: TransactionInfo,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+/**
+ * The open ledger (`ledger_index: "current"`) has not been closed or hashed yet,
+ * so only `closed`, `ledger_index`, `parent_hash` and `seqNum` are always there. [1]
+ * 1: https://xrpl.org/ledger.html#response-format
+ */
 #[derive(Deserialize, Debug)]
 pub struct NestedLedgerInfo {
-    pub accepted: bool,
-    pub account_hash: String,
-    pub close_flags: isize,
-    pub close_time: BigDecimal,
-    pub close_time_human: String,
-    pub close_time_resolution: BigDecimal,
+    pub accepted: Option<bool>,
+    pub account_hash: Option<String>,
+    /// See `no_consensus_time`.
+    pub close_flags: Option<isize>,
+    pub close_time: Option<BigDecimal>,
+    pub close_time_human: Option<String>,
+    /**
+     * How many seconds `close_time` is rounded to, between 10 and 120. rippled widens
+     * it when validators disagree on close times and narrows it again once they agree. [1]
+     * 1: https://xrpl.org/ledger-close-times.html
+     */
+    pub close_time_resolution: Option<BigDecimal>,
     pub closed: bool,
-    pub hash: String,
-    pub ledger_hash: String,
+    pub hash: Option<String>,
+    pub ledger_hash: Option<String>,
     #[serde(flatten)]
     pub ledger_index: LedgerIndex,
-    pub parent_close_time: BigDecimal,
+    pub parent_close_time: Option<BigDecimal>,
     pub parent_hash: String,
     pub seqNum: String,
-    pub totalCoins: String,
-    pub total_coins: BigDecimal,
-    pub transaction_hash: String,
+    pub totalCoins: Option<String>,
+    pub total_coins: Option<BigDecimal>,
+    pub transaction_hash: Option<String>,
     pub transactions: Option<Vec<TransactionInfo>>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct LedgerInfo {
-    pub ledger: Option<NestedLedgerInfo>,
-    pub ledger_hash: Option<String>,
-    #[serde(flatten)]
-    pub ledger_index: LedgerIndex,
-    pub status: String,
-    pub validated: bool,
+#[derive(Deserialize, Debug)]
+pub struct LedgerInfo {
+    pub ledger: Option<NestedLedgerInfo>,
+    pub ledger_hash: Option<String>,
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+    pub status: String,
+    pub validated: bool,
+}
+
+/**
+ * `accepted` through `queued` are only reported by rippled 1.5.0 and newer. [1]
+ * 1: https://xrpl.org/submit.html#response-format
+ */
+#[derive(Deserialize, Debug)]
+pub struct SubmitResult {
+    pub accepted: Option<bool>,
+    pub account_sequence_available: Option<BigDecimal>,
+    pub account_sequence_next: Option<BigDecimal>,
+    pub applied: Option<bool>,
+    pub broadcast: Option<bool>,
+    pub engine_result: TransactionResult,
+    pub engine_result_code: i64,
+    pub engine_result_message: String,
+    pub kept: Option<bool>,
+    pub open_ledger_cost: Option<BigDecimal>,
+    pub queued: Option<bool>,
+    pub status: String,
+    pub tx_blob: String,
+    pub tx_json: serde_json::Value,
+    pub validated_ledger_index: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BalanceEntry {
+    pub currency: String,
+    pub value: BigDecimal,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GatewayBalances {
+    pub account: Account,
+    pub assets: Option<std::collections::HashMap<String, Vec<BalanceEntry>>>,
+    pub balances: Option<std::collections::HashMap<String, Vec<BalanceEntry>>>,
+    pub frozen_balances: Option<std::collections::HashMap<String, Vec<BalanceEntry>>>,
+    pub obligations: Option<std::collections::HashMap<String, BigDecimal>>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+/**
+ * Currencies are either 3 letter codes or 40 hex character codes. [1]
+ * 1: https://xrpl.org/currency-formats.html#currency-codes
+ */
+#[derive(Deserialize, Debug)]
+pub struct AccountCurrencies {
+    pub ledger_hash: Option<String>,
+    #[serde(alias = "ledger_current_index")]
+    pub ledger_index: BigDecimal,
+    pub receive_currencies: Vec<String>,
+    pub send_currencies: Vec<String>,
+    pub validated: bool,
+}
+
+/**
+ * Plain English descriptions of the settings that differ from what `role` should use. [1]
+ * 1: https://xrpl.org/noripple_check.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct NoRippleCheck {
+    pub problems: Vec<String>,
+    pub transactions: Option<Vec<serde_json::Value>>,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+/**
+ * Whether `source_account` may pay `destination_account` directly, always true
+ * unless the destination has DepositAuth enabled. [1]
+ * 1: https://xrpl.org/deposit_authorized.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct DepositAuthorized {
+    pub deposit_authorized: bool,
+    pub destination_account: String,
+    pub ledger_hash: Option<String>,
+    pub source_account: String,
+    pub validated: Option<bool>,
+
+    #[serde(flatten)]
+    pub ledger_index: LedgerIndex,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ChannelAuthorize {
+    pub signature: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SignResult {
+    pub tx_blob: String,
+    pub tx_json: serde_json::Value,
+}
+
+/// `tx_json` has the signatures so far in `Signers`, this one included.
+#[derive(Deserialize, Debug)]
+pub struct SignForResult {
+    pub tx_blob: String,
+    pub tx_json: serde_json::Value,
+}
+
+impl SignForResult {
+    /// The `Signers` entry of `account`, `None` if it is missing or `Signers` is malformed.
+    pub fn signer(&self, account: &Account) -> Option<Signer> {
+        let (classic, _) = account.to_classic();
+        let signers: Vec<SignerEntry> =
+            serde_json::from_value(self.tx_json.get("Signers")?.clone()).ok()?;
+        signers
+            .into_iter()
+            .map(|entry| entry.Signer)
+            .find(|signer| signer.Account == classic.0)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ChannelVerify {
+    pub signature_verified: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LedgerCurrent {
+    pub ledger_current_index: BigDecimal,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LedgerClosed {
+    pub ledger_hash: String,
+    pub ledger_index: BigDecimal,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LastCloseInfo {
+    pub converge_time_s: BigDecimal,
+    pub proposers: BigDecimal,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ValidatedLedgerInfo {
+    pub age: Option<BigDecimal>,
+    pub base_fee_xrp: BigDecimal,
+    pub hash: String,
+    pub reserve_base_xrp: BigDecimal,
+    pub reserve_inc_xrp: BigDecimal,
+    pub seq: BigDecimal,
+}
+
+/**
+ * Fields like `validated_ledger` are left out while the server is still syncing. [1]
+ * 1: https://xrpl.org/server_info.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct ServerInfoDetails {
+    pub build_version: String,
+    #[serde(default)]
+    pub complete_ledgers: String,
+    pub hostid: Option<String>,
+    pub io_latency_ms: Option<BigDecimal>,
+    pub last_close: Option<LastCloseInfo>,
+    pub load_factor: BigDecimal,
+    pub peers: Option<BigDecimal>,
+    pub pubkey_node: Option<String>,
+    pub server_state: ServerState,
+    pub time: Option<String>,
+    pub uptime: Option<BigDecimal>,
+    #[serde(default)]
+    pub validated_ledger: Option<ValidatedLedgerInfo>,
+    pub validation_quorum: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ServerInfo {
+    pub info: ServerInfoDetails,
+    pub status: String,
+}
+
+/**
+ * How far along the server is with following the network. [1] A state a newer rippled
+ * adds lands in `Unknown` instead of failing the whole response.
+ * 1: https://xrpl.org/rippled-server-states.html
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerState {
+    Disconnected,
+    Connected,
+    Syncing,
+    Tracking,
+    Full,
+    Validating,
+    Proposing,
+    Unknown(String),
+}
+
+impl<'de> serde::Deserialize<'de> for ServerState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let state = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(match state.as_str() {
+            "disconnected" => ServerState::Disconnected,
+            "connected" => ServerState::Connected,
+            "syncing" => ServerState::Syncing,
+            "tracking" => ServerState::Tracking,
+            "full" => ServerState::Full,
+            "validating" => ServerState::Validating,
+            "proposing" => ServerState::Proposing,
+            _ => ServerState::Unknown(state),
+        })
+    }
+}
+
+impl Serialize for ServerState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl ServerState {
+    /// The name rippled uses, e.g. `"full"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServerState::Disconnected => "disconnected",
+            ServerState::Connected => "connected",
+            ServerState::Syncing => "syncing",
+            ServerState::Tracking => "tracking",
+            ServerState::Full => "full",
+            ServerState::Validating => "validating",
+            ServerState::Proposing => "proposing",
+            ServerState::Unknown(state) => state,
+        }
+    }
+
+    /// Fully caught up with the network, the states in which its answers are current.
+    pub fn is_synced(&self) -> bool {
+        matches!(
+            self,
+            ServerState::Full | ServerState::Validating | ServerState::Proposing
+        )
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ServerStateLastClose {
+    pub converge_time: BigDecimal,
+    pub proposers: BigDecimal,
+}
+
+/// Unlike `server_info`, fees and reserves are in drops.
+#[derive(Deserialize, Debug)]
+pub struct ServerStateValidatedLedger {
+    pub base_fee: Drops,
+    pub close_time: BigDecimal,
+    pub hash: String,
+    pub reserve_base: Drops,
+    pub reserve_inc: Drops,
+    pub seq: u64,
+}
+
+/**
+ * `server_info` with machine friendly numbers: load factors are unscaled
+ * (divide by `load_base`) and durations are in ms. [1]
+ * 1: https://xrpl.org/server_state.html
+ */
+#[derive(Deserialize, Debug)]
+pub struct ServerStateDetails {
+    pub build_version: String,
+    #[serde(default)]
+    pub complete_ledgers: String,
+    pub io_latency_ms: Option<BigDecimal>,
+    pub last_close: Option<ServerStateLastClose>,
+    pub load_base: BigDecimal,
+    pub load_factor: BigDecimal,
+    pub load_factor_fee_escalation: Option<BigDecimal>,
+    pub load_factor_fee_queue: Option<BigDecimal>,
+    pub load_factor_fee_reference: Option<BigDecimal>,
+    pub load_factor_server: Option<BigDecimal>,
+    pub peers: Option<BigDecimal>,
+    pub pubkey_node: Option<String>,
+    pub server_state: ServerState,
+    pub server_state_duration_us: Option<String>,
+    pub uptime: Option<BigDecimal>,
+    pub validated_ledger: Option<ServerStateValidatedLedger>,
+    pub validation_quorum: Option<BigDecimal>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ServerStateInfo {
+    pub state: ServerStateDetails,
+    pub status: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FeeDrops {
+    pub base_fee: Drops,
+    pub median_fee: Drops,
+    pub minimum_fee: Drops,
+    pub open_ledger_fee: Drops,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FeeLevels {
+    pub median_level: BigDecimal,
+    pub minimum_level: BigDecimal,
+    pub open_ledger_level: BigDecimal,
+    pub reference_level: BigDecimal,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FeeInfo {
+    pub current_ledger_size: BigDecimal,
+    pub current_queue_size: BigDecimal,
+    pub drops: FeeDrops,
+    pub expected_ledger_size: BigDecimal,
+    pub ledger_current_index: BigDecimal,
+    pub levels: FeeLevels,
+    pub max_queue_size: Option<BigDecimal>,
+    pub status: String,
+}
+
+#[test]
+fn json_test() {
+    let _: LedgerInfo =
+        serde_json::from_reader(std::fs::File::open("ledger.json").unwrap()).unwrap();
+}
+
+#[test]
+fn account_validate_test() {
+    assert!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".parse::<Account>().is_ok());
+    assert!("r9HwsqBnAUN4nF6nDqxd4sgP8DrDnDcZP3".parse::<Account>().is_ok());
+    assert!("rrrrrrrrrrrrrrrrrrrrBZbvji".parse::<Account>().is_ok());
+    let corrupted = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi".parse::<Account>();
+    assert!(
+        corrupted.as_ref().unwrap_err().contains("checksum"),
+        "Expected a checksum error, got {:?}",
+        corrupted
+    );
+    assert!("rHb9CJAWyB4rj91VRWn96DkukG4bwdty0h"
+        .parse::<Account>()
+        .is_err());
+}
+
+#[test]
+fn json_ledger_entry_type_test() {
+    let entry_type: LedgerEntryType = serde_json::from_str(r#""RippleState""#).unwrap();
+    assert_eq!(entry_type, LedgerEntryType::RippleState);
+    let entry_type: LedgerEntryType = serde_json::from_str(r#""Offer""#).unwrap();
+    assert_eq!(entry_type, LedgerEntryType::Offer);
+    let entry_type: LedgerEntryType = serde_json::from_str(r#""SomeFutureObject""#).unwrap();
+    assert_eq!(
+        entry_type,
+        LedgerEntryType::Unknown("SomeFutureObject".into())
+    );
+}
+
+#[test]
+fn json_affected_nodes_test() {
+    let ledger: LedgerInfo =
+        serde_json::from_reader(std::fs::File::open("ledger.json").unwrap()).unwrap();
+    let nodes: Vec<AffectedNodeInfo> = ledger
+        .ledger
+        .unwrap()
+        .transactions
+        .unwrap()
+        .into_iter()
+        .filter_map(|tx| tx.metaData)
+        .flat_map(|meta| meta.AffectedNodes)
+        .collect();
+    assert!(nodes.iter().any(|node| match node {
+        AffectedNodeInfo::CreatedNode(created) => created.LedgerEntryType == "Offer",
+        _ => false,
+    }));
+    assert!(nodes.iter().any(|node| match node {
+        AffectedNodeInfo::DeletedNode(deleted) => deleted.LedgerEntryType == "Offer",
+        _ => false,
+    }));
+    assert!(nodes.iter().any(|node| match node {
+        AffectedNodeInfo::ModifiedNode(modified) => modified.LedgerEntryType == "AccountRoot",
+        _ => false,
+    }));
+}
+
+#[test]
+fn json_delivered_amount_test() {
+    let meta: MetaTxInfo = serde_json::from_str(
+        r#"{
+  "AffectedNodes": [],
+  "DeliveredAmount": {
+    "currency": "USD",
+    "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    "value": "0.0001"
+  },
+  "TransactionIndex": 12,
+  "TransactionResult": "tesSUCCESS",
+  "delivered_amount": {
+    "currency": "USD",
+    "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    "value": "0.0001"
+  }
+}"#,
+    )
+    .unwrap();
+    match meta.delivered_amount {
+        Some(Balance::Other { ref value, .. }) => {
+            assert_eq!(value, &BigDecimal::from_str("0.0001").unwrap())
+        }
+        ref other => panic!("Expected an issued currency, got {:?}", other),
+    }
+    assert!(meta.DeliveredAmount.is_some());
+
+    let meta: MetaTxInfo = serde_json::from_str(
+        r#"{
+  "AffectedNodes": [],
+  "TransactionIndex": 3,
+  "TransactionResult": "tesSUCCESS",
+  "delivered_amount": "unavailable"
+}"#,
+    )
+    .unwrap();
+    match meta.delivered_amount {
+        Some(Balance::Unavailable) => {}
+        ref other => panic!("Expected unavailable, got {:?}", other),
+    }
+    assert_eq!(
+        serde_json::to_string(&Balance::Unavailable).unwrap(),
+        r#""unavailable""#
+    );
+}
+
+#[test]
+fn meta_delivered_test() {
+    let meta = |fields: &str| -> MetaTxInfo {
+        serde_json::from_str(&format!(
+            r#"{{ "AffectedNodes": [], "TransactionIndex": 0, "TransactionResult": "tesSUCCESS"{} }}"#,
+            fields
+        ))
+        .unwrap()
+    };
+    let delivered = |meta: MetaTxInfo| meta.delivered().and_then(|amount| amount.value());
+    assert_eq!(
+        delivered(meta(r#", "delivered_amount": "2000000""#)),
+        Some(BigDecimal::from(2))
+    );
+    assert_eq!(
+        delivered(meta(r#", "DeliveredAmount": "3000000""#)),
+        Some(BigDecimal::from(3))
+    );
+    assert_eq!(
+        delivered(meta(
+            r#", "delivered_amount": "2000000", "DeliveredAmount": "3000000""#
+        )),
+        Some(BigDecimal::from(2))
+    );
+    assert!(meta(r#", "delivered_amount": "unavailable""#)
+        .delivered()
+        .is_none());
+    assert!(meta("").delivered().is_none());
+}
+
+#[test]
+fn meta_sorted_nodes_test() {
+    let meta: MetaTxInfo = serde_json::from_str(
+        r#"{
+  "AffectedNodes": [
+    {
+      "ModifiedNode": {
+        "FinalFields": { "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "Balance": "99999988" },
+        "LedgerEntryType": "AccountRoot",
+        "LedgerIndex": "B5D9E1A9DA4B56FE1C5DC4A9E2F3D54D1A1C9D41BA3D4B5A9F6E1F2A3B4C5D6E",
+        "PreviousFields": { "Balance": "100000000" }
+      }
+    },
+    {
+      "CreatedNode": {
+        "LedgerEntryType": "RippleState",
+        "LedgerIndex": "EA4BF03B4700123CDFFB6EB09DC1D6E28D5CEB7F680FB00FC24BC1C3BB2DB959"
+      }
+    },
+    {
+      "DeletedNode": {
+        "LedgerEntryType": "Offer",
+        "LedgerIndex": "0A1B2C3D4E5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5C6D7E8F9"
+      }
+    },
+    {
+      "ModifiedNode": {
+        "LedgerEntryType": "AccountRoot",
+        "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+      }
+    }
+  ],
+  "TransactionIndex": 0,
+  "TransactionResult": "tesSUCCESS"
+}"#,
+    )
+    .unwrap();
+    let order: Vec<_> = meta
+        .sorted_nodes()
+        .into_iter()
+        .map(|node| (node.ledger_entry_type(), &node.ledger_index()[..4]))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("AccountRoot", "13F1"),
+            ("AccountRoot", "B5D9"),
+            ("Offer", "0A1B"),
+            ("RippleState", "EA4B"),
+        ]
+    );
+}
+
+#[test]
+fn json_server_info_test() {
+    let server_info: ServerInfo =
+        serde_json::from_reader(std::fs::File::open("server_info.json").unwrap()).unwrap();
+    assert_eq!(server_info.info.server_state, ServerState::Full);
+    assert_eq!(server_info.info.complete_ledgers, "32570-50461165");
+    let validated_ledger = server_info.info.validated_ledger.unwrap();
+    assert_eq!(validated_ledger.reserve_base_xrp, BigDecimal::from(20));
+    assert_eq!(validated_ledger.reserve_inc_xrp, BigDecimal::from(5));
+}
+
+#[test]
+fn json_fee_test() {
+    let fee: FeeInfo = serde_json::from_str(
+        r#"{
+  "current_ledger_size": "14",
+  "current_queue_size": "0",
+  "drops": {
+    "base_fee": "10",
+    "median_fee": "11000",
+    "minimum_fee": "10",
+    "open_ledger_fee": "10"
+  },
+  "expected_ledger_size": "24",
+  "ledger_current_index": 26575101,
+  "levels": {
+    "median_level": "281600",
+    "minimum_level": "256",
+    "open_ledger_level": "256",
+    "reference_level": "256"
+  },
+  "max_queue_size": "480",
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(fee.drops.median_fee, Drops(11_000));
+    assert_eq!(fee.ledger_current_index, BigDecimal::from_str("26575101").unwrap());
+}
+
+#[test]
+fn json_tx_test() {
+    let tx: TransactionInfo = serde_json::from_str(
+        r#"{
+  "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+  "Amount": "1000000",
+  "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "Fee": "12",
+  "Flags": 2147483648,
+  "LastLedgerSequence": 56865248,
+  "Sequence": 398,
+  "SigningPubKey": "028472865AF4CB32AA285834B57576B7290AA8C31B459047DB27E16F418D6A7166",
+  "TransactionType": "Payment",
+  "TxnSignature": "3045022100D64A32A506B86E880480CCB846EFA3F9665C9B11FDCA35D7124F53C486CC1D0402206EC8663308D91C928D1FDA5BB7BA2EE1C315887F039D73E1D37C8144FBACCE9F",
+  "date": 648248020,
+  "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+  "inLedger": 56865245,
+  "ledger_index": 56865245,
+  "meta": {
+    "AffectedNodes": [
+      {
+        "ModifiedNode": {
+          "FinalFields": {
+            "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+            "Balance": "1448396661",
+            "Flags": 0,
+            "OwnerCount": 0,
+            "Sequence": 399
+          },
+          "LedgerEntryType": "AccountRoot",
+          "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+          "PreviousFields": {
+            "Balance": "1449396673",
+            "Sequence": 398
+          },
+          "PreviousTxnID": "7ACA49CF86F89D3BA3ED1DEE0FB0B8CB60EA4D2F0D9798D46D3CF3EB7A183BF0",
+          "PreviousTxnLgrSeq": 56865244
+        }
+      }
+    ],
+    "TransactionIndex": 0,
+    "TransactionResult": "tesSUCCESS"
+  },
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert_eq!(tx.TransactionType, TransactionType::Payment);
+    assert!(tx.metaData.unwrap().TransactionResult.is_success());
+    assert_eq!(tx.ledger_index, Some(BigDecimal::from_str("56865245").unwrap()));
+
+    let pending: TransactionInfo = serde_json::from_str(
+        r#"{
+  "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+  "Amount": "1000000",
+  "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "Fee": "12",
+  "Sequence": 399,
+  "SigningPubKey": "028472865AF4CB32AA285834B57576B7290AA8C31B459047DB27E16F418D6A7166",
+  "TransactionType": "Payment",
+  "hash": "D53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert!(pending.ledger_index.is_none());
+    assert!(pending.metaData.is_none());
+}
+
+#[test]
+fn json_account_lines_test() {
+    let account_lines: AccountLines = serde_json::from_str(
+        r#"{
+  "account": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+  "ledger_current_index": 56867201,
+  "lines": [
+    {
+      "account": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+      "balance": "0",
+      "currency": "USD",
+      "limit": "0",
+      "limit_peer": "0",
+      "no_ripple": true,
+      "quality_in": 0,
+      "quality_out": 0
+    },
+    {
+      "account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+      "balance": "-0.012",
+      "currency": "USD",
+      "freeze": true,
+      "limit": "0",
+      "limit_peer": "1",
+      "quality_in": 0,
+      "quality_out": 0
+    }
+  ],
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert_eq!(account_lines.lines.len(), 2);
+    assert!(account_lines.lines[0].no_ripple);
+    assert!(!account_lines.lines[0].freeze);
+    assert!(account_lines.lines[1].freeze);
+    assert_eq!(
+        account_lines.lines[1].balance,
+        BigDecimal::from_str("-0.012").unwrap()
+    );
+}
+
+#[test]
+fn account_objects_params_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let params = AccountObjectsParams {
+        account: &account,
+        object_type: Some(AccountObjectType::PaymentChannel),
+        deletion_blockers_only: None,
+        ledger_index: Some(RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        }),
+        limit: Some(10),
+        marker: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "type": "payment_channel",
+            "ledger_index": "validated",
+            "limit": 10
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(AccountObjectType::SignerList).unwrap(),
+        serde_json::json!("signer_list")
+    );
+}
+
+#[test]
+fn json_account_objects_test() {
+    let account_objects: AccountObjects = serde_json::from_str(
+        r#"{
+  "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "account_objects": [
+    {
+      "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+      "BookDirectory": "50AD0A9E54D2B381288D535EB724E4275FFBF41580D28A925D038D7EA4C68000",
+      "BookNode": "0000000000000000",
+      "Flags": 65536,
+      "LedgerEntryType": "Offer",
+      "OwnerNode": "0000000000000000",
+      "PreviousTxnID": "F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF",
+      "PreviousTxnLgrSeq": 14524914,
+      "Sequence": 5,
+      "TakerGets": "79550000000",
+      "TakerPays": {
+        "currency": "USD",
+        "issuer": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        "value": "5"
+      },
+      "index": "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+    }
+  ],
+  "ledger_hash": "4C99E5F63C0D0B1C2283B4F5DCE2239F80CE92E8B1A6AED1E110C198FC96E659",
+  "ledger_index": 14380380,
+  "limit": 1,
+  "marker": "F60ADF645E78B69857D2E4AEC8B7742FEABC8431BD8611D099B428C3E816DF93,94A9F05FEF9A153229E2E997E64919FD75AAE2028C8153E8EBDB4440BD3ECBB5",
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert_eq!(account_objects.account_objects.len(), 1);
+    assert!(account_objects.marker.is_some());
+}
+
+#[test]
+fn book_offers_params_test() {
+    let issuer: Account = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".parse().unwrap();
+    let params = BookOffersParams {
+        taker_gets: BookAsset::xrp(),
+        taker_pays: BookAsset::issued("USD", &issuer),
+        taker: None,
+        ledger_index: None,
+        limit: Some(10),
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "taker_gets": { "currency": "XRP" },
+            "taker_pays": {
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"
+            },
+            "limit": 10
+        })
+    );
+}
+
+#[test]
+fn json_book_offers_test() {
+    let book_offers: BookOffers = serde_json::from_str(
+        r#"{
+  "ledger_current_index": 8696243,
+  "offers": [
+    {
+      "Account": "rnixnrMHHvR7ejMpJMRCWkaNrq3qREwMDu",
+      "BookDirectory": "7E5F614417C2D0A7CEFEB73C4AA773ED5B078DE2B5771F6D55055E4C405218EB",
+      "BookNode": "0000000000000000",
+      "Flags": 0,
+      "LedgerEntryType": "Offer",
+      "OwnerNode": "0000000000000000",
+      "PreviousTxnID": "BBE44D2B3B9AB5A2C43A61435AC40E585D4E8F6D1FEDCAF9C3FBA40DA4A44880",
+      "PreviousTxnLgrSeq": 8696236,
+      "Sequence": 1253,
+      "TakerGets": {
+        "currency": "USD",
+        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+        "value": "2.4"
+      },
+      "TakerPays": "20000000",
+      "index": "B6B5B5A9F6C7A0A7D8C1D9B3B4B0B2C1E6C0E9C7A3D5B8F1E0D2C3B4A5968778",
+      "owner_funds": "0.8584704900000001",
+      "quality": "8333333.333333333"
+    }
+  ],
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    let offer = &book_offers.offers[0];
+    match offer.TakerGets {
+        Balance::Other { ref currency, .. } => assert_eq!(currency.as_human(), "USD"),
+        ref other => panic!("Expected an issued currency, got {:?}", other),
+    }
+    match offer.TakerPays {
+        Balance::XRP(drops) => assert_eq!(drops, Drops(20_000_000)),
+        ref other => panic!("Expected XRP, got {:?}", other),
+    }
+    assert!(offer.quality.is_some());
+}
+
+#[test]
+fn json_ledger_current_test() {
+    let ledger_current: LedgerCurrent = serde_json::from_str(
+        r#"{
+  "ledger_current_index": 53773,
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ledger_current.ledger_current_index,
+        BigDecimal::from_str("53773").unwrap()
+    );
+}
+
+#[test]
+fn json_ledger_closed_test() {
+    let ledger_closed: LedgerClosed = serde_json::from_str(
+        r#"{
+  "ledger_hash": "17ACB57A0F73B5160713E81FE72B2AC9F6064541004E272BD09F257D57C30C02",
+  "ledger_index": 53772,
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(ledger_closed.ledger_index, BigDecimal::from_str("53772").unwrap());
+    assert_eq!(
+        ledger_closed.ledger_hash,
+        "17ACB57A0F73B5160713E81FE72B2AC9F6064541004E272BD09F257D57C30C02"
+    );
+}
+
+#[test]
+fn json_submit_test() {
+    let submit: SubmitResult = serde_json::from_str(
+        r#"{
+  "accepted": true,
+  "account_sequence_available": 362,
+  "account_sequence_next": 362,
+  "applied": true,
+  "broadcast": true,
+  "engine_result": "tesSUCCESS",
+  "engine_result_code": 0,
+  "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+  "kept": true,
+  "open_ledger_cost": "10",
+  "queued": false,
+  "status": "success",
+  "tx_blob": "1200002280000000240000016961D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9684000000000002710732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100A7CCD11455E47547FF617D5BFC15D120D9053DFD0536B044F10CA3631CD609E502203B61DEE4AC027C5743A1B56AF568D1E2B8E79BB9E9E14744AC87F38375C3C2F181144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754",
+  "tx_json": {
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Amount": {
+      "currency": "USD",
+      "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+      "value": "1"
+    },
+    "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+    "Fee": "10000",
+    "Flags": 2147483648,
+    "Sequence": 361,
+    "SigningPubKey": "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+    "TransactionType": "Payment",
+    "TxnSignature": "3045022100A7CCD11455E47547FF617D5BFC15D120D9053DFD0536B044F10CA3631CD609E502203B61DEE4AC027C5743A1B56AF568D1E2B8E79BB9E9E14744AC87F38375C3C2F1",
+    "hash": "5FD53C2A2A2D4A4BCC3EA3B2FAC4C3C8B7E1B91AFB5D5F3B5D13F5DF4AD4CB0D"
+  },
+  "validated_ledger_index": 21184416
+}"#,
+    )
+    .unwrap();
+    assert!(submit.engine_result.is_success());
+    assert_eq!(submit.engine_result_code, 0);
+    assert_eq!(submit.applied, Some(true));
+    assert_eq!(submit.tx_json["TransactionType"], "Payment");
+}
+
+#[test]
+fn submit_multisigned_test() {
+    let tx_json = serde_json::json!({
+        "Account": "rEuLyBCvcw4CFmzv8RepSiAoNgF8tTGJQC",
+        "Fee": "30000",
+        "Flags": 262144,
+        "LimitAmount": {
+            "currency": "USD",
+            "issuer": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "value": "10"
+        },
+        "Sequence": 2,
+        "Signers": [{
+            "Signer": {
+                "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                "SigningPubKey": "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF",
+                "TxnSignature": "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E5"
+            }
+        }],
+        "SigningPubKey": "",
+        "TransactionType": "TrustSet"
+    });
+    let params = SubmitMultisignedParams::new(tx_json.clone(), None).unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({ "tx_json": tx_json })
+    );
+    let mut unsigned = tx_json.clone();
+    unsigned["Signers"] = serde_json::json!([]);
+    assert!(SubmitMultisignedParams::new(unsigned.clone(), None).is_err());
+    unsigned.as_object_mut().unwrap().remove("Signers");
+    assert!(SubmitMultisignedParams::new(unsigned, None).is_err());
+
+    let submit: SubmitResult = serde_json::from_str(
+        r#"{
+  "engine_result": "tesSUCCESS",
+  "engine_result_code": 0,
+  "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+  "status": "success",
+  "tx_blob": "1200142200040000240000000263D5038D7EA4C6800000000000000000000000000055534400000000000A8963C5981E6F1E77C9A2C30BA7CA7D13F2727868400000000000753073008114A3780F5CB5A44D366520FC44055E8ED44D9A2270F3E010732102B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF74473045022100",
+  "tx_json": {
+    "Account": "rEuLyBCvcw4CFmzv8RepSiAoNgF8tTGJQC",
+    "Fee": "30000",
+    "Sequence": 2,
+    "SigningPubKey": "",
+    "TransactionType": "TrustSet",
+    "hash": "BD636194C48FD7A100DE4C972336534C8E710FD008C0F3CF7BC5BF34DAF3C3E6"
+  }
+}"#,
+    )
+    .unwrap();
+    assert!(submit.engine_result.is_success());
+    assert_eq!(submit.tx_json["SigningPubKey"], "");
+}
+
+#[test]
+fn gateway_balances_test() {
+    let account: Account = "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q".parse().unwrap();
+    let params = GatewayBalancesParams {
+        account: &account,
+        strict: Some(true),
+        hotwallet: Some(vec!["rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ".into()]),
+        ledger_index: Some(RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        }),
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "account": "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q",
+            "strict": true,
+            "hotwallet": "rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ",
+            "ledger_index": "validated"
+        })
+    );
+
+    let gateway_balances: GatewayBalances = serde_json::from_str(
+        r#"{
+  "account": "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q",
+  "assets": {
+    "r9F6wk8HkXrgYWoJ7fsv4VrUBVoqDVtzkH": [
+      {
+        "currency": "BTC",
+        "value": "5444166510000000e-26"
+      }
+    ]
+  },
+  "balances": {
+    "rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ": [
+      {
+        "currency": "EUR",
+        "value": "29826.1965999999"
+      }
+    ]
+  },
+  "ledger_hash": "61DDBF304AF6E8101576BF161D447CA8E4F0170DDFBEAFFD993DC9383D443388",
+  "ledger_index": 14483195,
+  "obligations": {
+    "BTC": "5908.324927635308",
+    "EUR": "992471.7419793958"
+  },
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    let balances = gateway_balances.balances.unwrap();
+    assert_eq!(
+        balances["rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ"][0].currency,
+        "EUR"
+    );
+    assert_eq!(
+        gateway_balances.obligations.unwrap()["BTC"],
+        BigDecimal::from_str("5908.324927635308").unwrap()
+    );
+}
+
+#[test]
+fn json_account_currencies_test() {
+    let account_currencies: AccountCurrencies = serde_json::from_str(
+        r#"{
+  "ledger_index": 11775844,
+  "receive_currencies": [
+    "BTC",
+    "CNY",
+    "DYM",
+    "EUR",
+    "JOE",
+    "MXN",
+    "USD",
+    "015841551A748AD2C1F76FF6ECB0CCCD00000000"
+  ],
+  "send_currencies": [
+    "ASP",
+    "BTC",
+    "CHF",
+    "CNY",
+    "DYM",
+    "EUR",
+    "JOE",
+    "JPY",
+    "MXN",
+    "USD"
+  ],
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert_eq!(account_currencies.receive_currencies.len(), 8);
+    assert_eq!(
+        account_currencies.receive_currencies[7],
+        "015841551A748AD2C1F76FF6ECB0CCCD00000000"
+    );
+    assert_eq!(account_currencies.send_currencies.len(), 10);
+    assert!(account_currencies.validated);
+}
+
+#[test]
+fn json_ripple_path_find_test() {
+    let path_find: RipplePathFind = serde_json::from_str(
+        r#"{
+  "alternatives": [
+    {
+      "paths_canonical": [],
+      "paths_computed": [
+        [
+          {
+            "currency": "USD",
+            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "type": 48,
+            "type_hex": "0000000000000030"
+          },
+          {
+            "account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "type": 1,
+            "type_hex": "0000000000000001"
+          }
+        ]
+      ],
+      "source_amount": "256987"
+    },
+    {
+      "paths_canonical": [],
+      "paths_computed": [],
+      "source_amount": {
+        "currency": "USD",
+        "issuer": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+        "value": "1"
+      }
+    }
+  ],
+  "destination_account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "destination_currencies": [
+    "USD",
+    "XRP"
+  ],
+  "ledger_current_index": 55,
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert_eq!(path_find.alternatives.len(), 2);
+    let path = &path_find.alternatives[0].paths_computed[0];
+    assert_eq!(path[0].currency.as_ref().map(Currency::as_human), Some("USD".into()));
+    assert_eq!(
+        path[1].account.as_deref(),
+        Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")
+    );
+    assert!(path_find.alternatives[0].source_amount.as_xrp().is_some());
+    assert!(path_find.alternatives[1].source_amount.as_xrp().is_none());
+}
+
+#[test]
+fn json_memos_test() {
+    let tx: TransactionInfo = serde_json::from_str(
+        r#"{
+  "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+  "Amount": "1000000",
+  "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "Fee": "12",
+  "Memos": [
+    {
+      "Memo": {
+        "MemoData": "494E562D3230323030393031",
+        "MemoFormat": "746578742F706C61696E",
+        "MemoType": "696E766F696365"
+      }
+    },
+    {
+      "Memo": {
+        "MemoData": "C328"
+      }
+    }
+  ],
+  "Sequence": 400,
+  "SigningPubKey": "028472865AF4CB32AA285834B57576B7290AA8C31B459047DB27E16F418D6A7166",
+  "TransactionType": "Payment",
+  "hash": "E53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+}"#,
+    )
+    .unwrap();
+    let memos = tx.Memos.unwrap();
+    assert_eq!(memos[0].Memo.data_utf8(), Some("INV-20200901".into()));
+    assert_eq!(memos[0].Memo.format_utf8(), Some("text/plain".into()));
+    assert_eq!(memos[0].Memo.type_utf8(), Some("invoice".into()));
+    assert_eq!(memos[1].Memo.data_utf8(), None);
+    assert_eq!(memos[1].Memo.type_utf8(), None);
+}
+
+#[test]
+fn json_multisign_test() {
+    let tx: TransactionInfo = serde_json::from_str(
+        r#"{
+  "Account": "rEuLyBCvcw4CFmzv8RepSiAoNgF8tTGJQC",
+  "Amount": "1000000",
+  "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "Fee": "30000",
+  "Flags": 262144,
+  "Sequence": 4,
+  "Signers": [
+    {
+      "Signer": {
+        "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+        "SigningPubKey": "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF",
+        "TxnSignature": "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E5"
+      }
+    },
+    {
+      "Signer": {
+        "Account": "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v",
+        "SigningPubKey": "028FFB276505F9AC3F57E8D5242B386A597EF6C40A7999F37F1948636FD484E25B",
+        "TxnSignature": "30440220680BBD745004E9CFB6B13A137F505FB92298AD309071D16C7B982825188FD1AE022004200B1F7E4A6A84BB0E4FC09E1E3BA2B66EBD32F0E6D121A34BA3B04AD99BC1"
+      }
+    }
+  ],
+  "SigningPubKey": "",
+  "TransactionType": "Payment",
+  "hash": "BD636194C48FD7A100DE4C972336534C8E710FD008C0F3CF7BC5BF34DAF3C3E6"
+}"#,
+    )
+    .unwrap();
+    let signers = tx.Signers.unwrap();
+    assert_eq!(signers.len(), 2);
+    assert_eq!(signers[1].Signer.Account, "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v");
+    assert_eq!(tx.SigningPubKey, Some("".into()));
+    assert!(tx.TxnSignature.is_none());
+}
+
+#[test]
+fn json_ledger_data_test() {
+    let ledger_data: LedgerData = serde_json::from_str(
+        r#"{
+  "ledger_hash": "842B57C1CC0613299A686D3E9F310EC0422C84D3911E5056389AA7E5808A93C8",
+  "ledger_index": "6885842",
+  "marker": "0002A4CB1E0A5A5B1A9C4B6CFF04C3D5BC0E277B62DE2D6C36A1B7D8B7B9B098",
+  "state": [
+    {
+      "Account": "rKKzk9ghA2iuy3imqMXUHJqdRPMtNDGf4c",
+      "Balance": "893730848",
+      "Flags": 0,
+      "LedgerEntryType": "AccountRoot",
+      "OwnerCount": 0,
+      "PreviousTxnID": "C204A65CF2542946289A3358C67D991B5E135FABFA89F271DBA7A150C08CA046",
+      "PreviousTxnLgrSeq": 6487716,
+      "Sequence": 1,
+      "index": "00001A2969BE1FC85F1D7A55282FA2E6D95C71D2E4B9C0FDD3D9994F3C00FF8F"
+    }
+  ],
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert_eq!(ledger_data.state.len(), 1);
+    assert_eq!(ledger_data.state[0]["LedgerEntryType"], "AccountRoot");
+    assert_eq!(
+        ledger_data.marker,
+        Some(serde_json::json!(
+            "0002A4CB1E0A5A5B1A9C4B6CFF04C3D5BC0E277B62DE2D6C36A1B7D8B7B9B098"
+        ))
+    );
+}
+
+#[test]
+fn json_open_ledger_test() {
+    let open: LedgerInfo = serde_json::from_str(
+        r#"{
+  "ledger": {
+    "closed": false,
+    "ledger_index": "56865292",
+    "parent_hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+    "seqNum": "56865292"
+  },
+  "ledger_current_index": 56865292,
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    let ledger = open.ledger.unwrap();
+    assert!(!ledger.closed);
+    assert!(ledger.hash.is_none());
+    assert!(ledger.close_time_human.is_none());
+    assert!(ledger.totalCoins.is_none());
 }
 
-jsonrpc_client!(pub struct XRPClient {
-    single:
-        pub fn account_info(&self, params: AccountInfoParams) -> Result<AccountInfo>;
-        pub fn account_tx(&self, params: AccountTxParams) -> Result<AccountTx>;
-        pub fn ledger(&self, params: LedgerInfoParams) -> Result<LedgerInfo>;
-    enum:
-});
+#[test]
+fn noripple_check_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    assert!("issuer".parse::<NoRippleRole>().is_err());
+    let params = NoRippleCheckParams {
+        account: &account,
+        role: "gateway".parse().unwrap(),
+        transactions: Some(true),
+        limit: Some(2),
+        ledger_index: Some(RequestLedgerIndex::StrValue {
+            ledger_index: "current".into(),
+        }),
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "role": "gateway",
+            "transactions": true,
+            "limit": 2,
+            "ledger_index": "current"
+        })
+    );
+
+    let check: NoRippleCheck = serde_json::from_str(
+        r#"{
+  "ledger_current_index": 14380381,
+  "problems": [
+    "You should immediately set your default ripple flag",
+    "You should clear the no ripple flag on your XAU line to r3vi7mWxru9rJCxETCyA1CHvzL96eZWx5z"
+  ],
+  "status": "success",
+  "transactions": [
+    {
+      "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+      "Fee": 10000,
+      "Sequence": 1406,
+      "SetFlag": 8,
+      "TransactionType": "AccountSet"
+    },
+    {
+      "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+      "Fee": 10000,
+      "Flags": 262144,
+      "LimitAmount": {
+        "currency": "XAU",
+        "issuer": "r3vi7mWxru9rJCxETCyA1CHvzL96eZWx5z",
+        "value": "0"
+      },
+      "Sequence": 1407,
+      "TransactionType": "TrustSet"
+    }
+  ],
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert_eq!(check.problems.len(), 2);
+    assert_eq!(check.transactions.unwrap()[1]["TransactionType"], "TrustSet");
+}
 
 #[test]
-fn json_test() {
-    let _: LedgerInfo =
-        serde_json::from_reader(std::fs::File::open("ledger.json").unwrap()).unwrap();
+fn json_deposit_authorized_test() {
+    let authorized: DepositAuthorized = serde_json::from_str(
+        r#"{
+  "deposit_authorized": true,
+  "destination_account": "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8",
+  "ledger_hash": "BD03A10653ED9D77DCA859B7A735BF0580088A8F287FA2C5403E0A19C58EF322",
+  "ledger_index": 8,
+  "source_account": "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de",
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert!(authorized.deposit_authorized);
+
+    let unauthorized: DepositAuthorized = serde_json::from_str(
+        r#"{
+  "deposit_authorized": false,
+  "destination_account": "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8",
+  "ledger_current_index": 9,
+  "source_account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert!(!unauthorized.deposit_authorized);
+    assert_eq!(unauthorized.validated, Some(false));
+}
+
+#[test]
+fn json_transaction_entry_test() {
+    let entry: TransactionEntry = serde_json::from_str(
+        r#"{
+  "ledger_hash": "793E56131D8D4ABFB27FA383BFC44F2978B046E023FF46C588D7E0C874C2472A",
+  "ledger_index": 56865245,
+  "metadata": {
+    "AffectedNodes": [
+      {
+        "ModifiedNode": {
+          "FinalFields": {
+            "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+            "Balance": "1448396661",
+            "Flags": 0,
+            "OwnerCount": 0,
+            "Sequence": 399
+          },
+          "LedgerEntryType": "AccountRoot",
+          "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+          "PreviousFields": {
+            "Balance": "1449396673",
+            "Sequence": 398
+          },
+          "PreviousTxnID": "7ACA49CF86F89D3BA3ED1DEE0FB0B8CB60EA4D2F0D9798D46D3CF3EB7A183BF0",
+          "PreviousTxnLgrSeq": 56865244
+        }
+      }
+    ],
+    "TransactionIndex": 0,
+    "TransactionResult": "tesSUCCESS",
+    "delivered_amount": "1000000"
+  },
+  "status": "success",
+  "tx_json": {
+    "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+    "Amount": "1000000",
+    "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Fee": "12",
+    "Flags": 2147483648,
+    "LastLedgerSequence": 56865248,
+    "Sequence": 398,
+    "SigningPubKey": "028472865AF4CB32AA285834B57576B7290AA8C31B459047DB27E16F418D6A7166",
+    "TransactionType": "Payment",
+    "TxnSignature": "3045022100D64A32A506B86E880480CCB846EFA3F9665C9B11FDCA35D7124F53C486CC1D0402206EC8663308D91C928D1FDA5BB7BA2EE1C315887F039D73E1D37C8144FBACCE9F",
+    "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+  },
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert!(entry.metadata.TransactionResult.is_success());
+    assert_eq!(
+        entry.tx_json.hash,
+        "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+    );
+    assert!(entry.tx_json.metaData.is_none());
+}
+
+#[test]
+fn json_string_fee_test() {
+    let tx: TransactionInfo = serde_json::from_str(
+        r#"{
+  "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+  "Fee": "12",
+  "Sequence": 398,
+  "TransactionType": "AccountSet",
+  "hash": "F53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(tx.Fee, Some(Drops(12)));
+    assert_eq!(tx.Sequence, Some(398));
+    assert!(serde_json::from_str::<TransactionInfo>(
+        r#"{
+  "Account": "rnUy2SHTrB9DubsPmkJZUXTf5FcNDGrYEA",
+  "Fee": "12.5",
+  "Sequence": 398,
+  "TransactionType": "AccountSet",
+  "hash": "F53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn json_server_state_test() {
+    let full: ServerStateInfo = serde_json::from_str(
+        r#"{
+  "state": {
+    "build_version": "1.5.0",
+    "complete_ledgers": "55414143-55414284",
+    "io_latency_ms": 1,
+    "jq_trans_overflow": "0",
+    "last_close": {
+      "converge_time": 2001,
+      "proposers": 34
+    },
+    "load_base": 256,
+    "load_factor": 256,
+    "load_factor_fee_escalation": 256,
+    "load_factor_fee_queue": 256,
+    "load_factor_fee_reference": 256,
+    "load_factor_server": 256,
+    "peers": 21,
+    "pubkey_node": "n9KuRDsZWoos2bMAkLEYZ43ytBcooVGs7ksyBW2p7Aw2zCwS1Sgg",
+    "server_state": "full",
+    "server_state_duration_us": "4742279207",
+    "time": "2020-Jun-10 23:35:39.373797 UTC",
+    "uptime": 4743,
+    "validated_ledger": {
+      "base_fee": 10,
+      "close_time": 645111330,
+      "hash": "F4AAE088486881FD8EDEF5D35D2D549415E1CA2C6C935CB817A6F67D7A902A90",
+      "reserve_base": 20000000,
+      "reserve_inc": 5000000,
+      "seq": 55414284
+    },
+    "validation_quorum": 28
+  },
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(full.state.server_state, ServerState::Full);
+    assert!(full.state.server_state.is_synced());
+    assert_eq!(
+        full.state.validated_ledger.unwrap().reserve_base,
+        Drops(20_000_000)
+    );
+
+    let syncing: ServerStateInfo = serde_json::from_str(
+        r#"{
+  "state": {
+    "build_version": "1.5.0",
+    "complete_ledgers": "empty",
+    "load_base": 256,
+    "load_factor": 256,
+    "peers": 3,
+    "server_state": "syncing",
+    "server_state_duration_us": "1520768",
+    "uptime": 12
+  },
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(syncing.state.server_state, ServerState::Syncing);
+    assert!(!syncing.state.server_state.is_synced());
+    assert!(syncing.state.validated_ledger.is_none());
+}
+
+#[test]
+fn server_state_unknown_test() {
+    let state: ServerState = serde_json::from_str(r#""catching_up""#).unwrap();
+    assert_eq!(state, ServerState::Unknown("catching_up".into()));
+    assert!(!state.is_synced());
+    assert_eq!(serde_json::to_value(&state).unwrap(), "catching_up");
+    assert_eq!(serde_json::to_value(ServerState::Full).unwrap(), "full");
+}
+
+#[test]
+fn json_account_offers_test() {
+    let account_offers: AccountOffers = serde_json::from_str(
+        r#"{
+  "account": "rpP2JgiMyTF5jR5hLG3xHCPi1knBb1v9cM",
+  "ledger_current_index": 18539596,
+  "offers": [
+    {
+      "flags": 0,
+      "quality": "0.000000007599140009999998",
+      "seq": 6578020,
+      "taker_gets": "29740867287",
+      "taker_pays": {
+        "currency": "USD",
+        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+        "value": "226.0050145327418"
+      }
+    },
+    {
+      "expiration": 617144498,
+      "flags": 131072,
+      "quality": "176.8372016084",
+      "seq": 6578022,
+      "taker_gets": {
+        "currency": "EUR",
+        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+        "value": "10"
+      },
+      "taker_pays": "1768372016"
+    }
+  ],
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert_eq!(account_offers.offers.len(), 2);
+    let offer = &account_offers.offers[0];
+    assert_eq!(offer.taker_gets.as_xrp(), Some(BigDecimal::from_str("29740.867287").unwrap()));
+    match offer.taker_pays {
+        Balance::Other { ref currency, .. } => assert_eq!(currency.as_human(), "USD"),
+        ref other => panic!("Expected an issued currency, got {:?}", other),
+    }
+    assert_eq!(account_offers.offers[1].expiration, Some(617_144_498));
+}
+
+#[test]
+fn channel_authorize_test() {
+    let channel_id = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+    let secret = Some("snoPBrXtMeMyMHUVTgbuqAfg1SUTb".to_owned());
+    let params = ChannelAuthorizeParams::new(
+        channel_id.into(),
+        Drops(1_000_000),
+        secret.clone(),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap()["amount"],
+        serde_json::json!("1000000")
+    );
+    assert!(!format!("{:?}", params).contains("snoPBrXtMeMyMHUVTgbuqAfg1SUTb"));
+    assert!(format!("{:?}", params).contains("secret: Some(<redacted>)"));
+    let none = ChannelAuthorizeParams::new(channel_id.into(), Drops(1), None, None, None, None);
+    assert!(none.is_err());
+    let passphrase = Some("masterpassphrase".to_owned());
+    let two =
+        ChannelAuthorizeParams::new(channel_id.into(), Drops(1), secret, None, None, passphrase);
+    assert!(two.is_err());
+
+    let authorize: ChannelAuthorize = serde_json::from_str(
+        r#"{
+  "signature": "304402204EF0AFB78AC23ED1C472E74F4299C0C21F1B21D07EFC0A3838A420F76D783A400220154FB11B6F54320666E4C36CA7F686C16A3A0456800BBC43746F34AF50290064",
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert!(authorize.signature.starts_with("3044"));
+}
+
+#[test]
+fn sign_test() {
+    let tx_json = serde_json::json!({
+        "TransactionType": "Payment",
+        "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+        "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+        "Amount": "1000000"
+    });
+    let secret = Some("snoPBrXtMeMyMHUVTgbuqAfg1SUTb".to_owned());
+    let params = SignParams::new(tx_json.clone(), secret.clone(), None).unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap()["secret"],
+        "snoPBrXtMeMyMHUVTgbuqAfg1SUTb"
+    );
+    assert!(!format!("{:?}", params).contains("snoPBrXtMeMyMHUVTgbuqAfg1SUTb"));
+    assert!(SignParams::new(tx_json.clone(), None, None).is_err());
+    assert!(SignParams::new(tx_json, secret.clone(), secret).is_err());
+
+    let signed: XrpResponse<SignResult> = serde_json::from_str(
+        r#"{
+  "status": "success",
+  "tx_blob": "120000228000000024000000026140000000000F424068400000000000000A732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA98314B5F762798A53D543A014CAF8B297CFF8F2F937E8",
+  "tx_json": {
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Amount": "1000000",
+    "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+    "Fee": "10",
+    "Flags": 2147483648,
+    "Sequence": 2,
+    "SigningPubKey": "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+    "TransactionType": "Payment",
+    "TxnSignature": "3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE",
+    "hash": "EE0866A824ECDF58E3D259593E2073A847ECC7D3BDC59482365968C179798964"
+  }
+}"#,
+    )
+    .unwrap();
+    let signed = signed.into_result().unwrap();
+    assert_eq!(decode_tx_blob(&signed.tx_blob).unwrap().hash, signed.tx_json["hash"]);
+    assert_eq!(signed.tx_json["Fee"], "10");
+    assert!(signed.tx_json["TxnSignature"].is_string());
+}
+
+#[test]
+fn sign_for_test() {
+    let signer: Account = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".parse().unwrap();
+    let tx_json = serde_json::json!({
+        "TransactionType": "TrustSet",
+        "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+        "Fee": "30000",
+        "Flags": 262144,
+        "LimitAmount": {
+            "currency": "USD",
+            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "value": "100"
+        },
+        "Sequence": 2,
+        "SigningPubKey": ""
+    });
+    let secret = Some("snoPBrXtMeMyMHUVTgbuqAfg1SUTb".to_owned());
+    let params = SignForParams::new(&signer, tx_json.clone(), secret.clone(), None).unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap()["account"],
+        "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW"
+    );
+    assert!(!format!("{:?}", params).contains("snoPBrXtMeMyMHUVTgbuqAfg1SUTb"));
+    assert!(SignForParams::new(&signer, tx_json.clone(), None, None).is_err());
+    assert!(SignForParams::new(&signer, tx_json, secret.clone(), secret).is_err());
+
+    let signed: SignForResult = serde_json::from_str(
+        r#"{
+  "status": "success",
+  "tx_blob": "1200142200040000240000000263D5038D7EA4C6800000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D1684000000000007530730081144B4E9C06F24296074F7BC48F92A97916C6DC5EA9F3E010732102B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF744730450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E58114204288D2E47F8EF6C99BCC457966320D12409711E1F1",
+  "tx_json": {
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Fee": "30000",
+    "Flags": 262144,
+    "LimitAmount": {
+      "currency": "USD",
+      "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+      "value": "100"
+    },
+    "Sequence": 2,
+    "Signers": [
+      {
+        "Signer": {
+          "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+          "SigningPubKey": "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF",
+          "TxnSignature": "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E5"
+        }
+      }
+    ],
+    "SigningPubKey": "",
+    "TransactionType": "TrustSet",
+    "hash": "724AE20A4D2A2388A452266607565D439B4F55F3241BE4AF5461FFE6EC21EDD9"
+  }
+}"#,
+    )
+    .unwrap();
+    assert_eq!(decode_tx_blob(&signed.tx_blob).unwrap().hash, signed.tx_json["hash"]);
+    let entry = signed.signer(&signer).unwrap();
+    assert_eq!(
+        entry.SigningPubKey,
+        "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF"
+    );
+    assert!(entry.TxnSignature.starts_with("3045"));
+    let other: Account = "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n".parse().unwrap();
+    assert!(signed.signer(&other).is_none());
+}
+
+#[test]
+fn json_channel_verify_test() {
+    let verified: ChannelVerify =
+        serde_json::from_str(r#"{"signature_verified": true, "status": "success"}"#).unwrap();
+    assert!(verified.signature_verified);
+    let rejected: ChannelVerify =
+        serde_json::from_str(r#"{"signature_verified": false, "status": "success"}"#).unwrap();
+    assert!(!rejected.signature_verified);
+}
+
+#[test]
+fn json_account_channels_test() {
+    let account_channels: AccountChannels = serde_json::from_str(
+        r#"{
+  "account": "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH",
+  "channels": [
+    {
+      "account": "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH",
+      "amount": "1000",
+      "balance": "0",
+      "channel_id": "C7F634794B79DB40E87179A9D1BF05D05797AE7E92DF8E93FD6656E8C4BE3AE7",
+      "destination_account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+      "public_key": "aBR7mdD75Ycs8DRhMgQ4EMUEmBArF8SEh1hfjrT2V9DQTLNbJVqw",
+      "public_key_hex": "03CFD18E689434F032A4E84C63E2A3A6472D684EAF4FD52CA67742F3E24BAE81B2",
+      "settle_delay": 60
+    },
+    {
+      "account": "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH",
+      "amount": "25000000",
+      "balance": "1000000",
+      "channel_id": "E35708503B3C3143FB522D749AAFCC296E8060F0FB371A9A56FAE0B1ED127366",
+      "destination_account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+      "destination_tag": 1002341,
+      "expiration": 547073182,
+      "settle_delay": 3600
+    }
+  ],
+  "ledger_hash": "27F530E5C93ED5C13994812787C1ED073C822BAEC7597964608F2C049C2ACD2D",
+  "ledger_index": 71766343,
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert_eq!(account_channels.channels.len(), 2);
+    let channel = &account_channels.channels[1];
+    assert_eq!(channel.amount, Drops(25_000_000));
+    assert_eq!(channel.balance, Drops(1_000_000));
+    assert_eq!(channel.expiration, Some(547_073_182));
+    assert!(channel.public_key.is_none());
+}
+
+#[test]
+fn json_account_nfts_test() {
+    let account_nfts: AccountNfts = serde_json::from_str(
+        r#"{
+  "account": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+  "account_nfts": [
+    {
+      "Flags": 1,
+      "Issuer": "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm",
+      "NFTokenID": "00010000A7CAD27B688D14BA1A9FA5366554D6ADCF9CE0875B974D9F00000004",
+      "NFTokenTaxon": 0,
+      "URI": "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C677478716935357362716F",
+      "nft_serial": 4
+    }
+  ],
+  "ledger_current_index": 17,
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    let nft = &account_nfts.account_nfts[0];
+    assert_eq!(nft.nft_serial, 4);
+    assert_eq!(nft.TransferFee, None);
+    assert_eq!(
+        nft.decode_uri(),
+        Some("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtxqi55sbqo".into())
+    );
+}
+
+#[test]
+fn json_nft_buy_offers_test() {
+    let buy_offers: NftOffers = serde_json::from_str(
+        r#"{
+  "nft_id": "00090000D0B007439B080E9B05BF62403911301A7B1F0CFAA048C0A200000007",
+  "offers": [
+    {
+      "amount": "1500",
+      "flags": 0,
+      "nft_offer_index": "3212D26DB00031889D4EF7D9129BB0FA673B5B40B1759564486C0F0946BA203F",
+      "owner": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx"
+    }
+  ],
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    let offer = &buy_offers.offers[0];
+    assert_eq!(offer.flags, 0);
+    assert!(offer.destination.is_none());
+    match offer.amount {
+        Balance::XRP(drops) => assert_eq!(drops, Drops(1500)),
+        ref other => panic!("expected XRP, got {:?}", other),
+    }
+}
+
+#[test]
+fn json_nft_sell_offers_test() {
+    let sell_offers: NftOffers = serde_json::from_str(
+        r#"{
+  "nft_id": "00090000D0B007439B080E9B05BF62403911301A7B1F0CFAA048C0A200000007",
+  "offers": [
+    {
+      "amount": {
+        "currency": "USD",
+        "issuer": "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm",
+        "value": "12.5"
+      },
+      "destination": "rGJUF4PvVkMNxG6Bg6AKg3avhrtQyAffcm",
+      "expiration": 740000000,
+      "flags": 1,
+      "nft_offer_index": "9E28E366573187F8E5B85CE301F229E061A619EE5A589EF740088F8843BF10A1",
+      "owner": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx"
+    }
+  ],
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    let offer = &sell_offers.offers[0];
+    assert_eq!(offer.flags, 1);
+    assert_eq!(offer.expiration, Some(740_000_000));
+    match offer.amount {
+        Balance::Other { ref value, .. } => {
+            assert_eq!(value, &BigDecimal::from_str("12.5").unwrap())
+        }
+        ref other => panic!("expected an issued amount, got {:?}", other),
+    }
+}
+
+#[test]
+fn json_account_data_u32_bounds_test() {
+    let account_data = |sequence: &str| {
+        serde_json::from_str::<AccountData>(&format!(
+            r#"{{
+  "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+  "Balance": "999999999960",
+  "Flags": 0,
+  "LedgerEntryType": "AccountRoot",
+  "OwnerCount": 0,
+  "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+  "PreviousTxnLgrSeq": 3,
+  "Sequence": {},
+  "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+}}"#,
+            sequence
+        ))
+    };
+    assert_eq!(account_data("4294967295").unwrap().Sequence, u32::MAX);
+    assert!(account_data("4294967296").is_err());
+    assert!(account_data("6.5").is_err());
+}
+
+#[test]
+fn json_account_info_signer_lists_test() {
+    let info: AccountInfo = serde_json::from_str(
+        r#"{
+  "account_data": {
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Balance": "999999999960",
+    "Flags": 0,
+    "LedgerEntryType": "AccountRoot",
+    "OwnerCount": 1,
+    "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+    "PreviousTxnLgrSeq": 3,
+    "Sequence": 6,
+    "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F",
+    "signer_lists": [
+      {
+        "Flags": 0,
+        "LedgerEntryType": "SignerList",
+        "OwnerNode": "0",
+        "PreviousTxnID": "B2E0C400F13AC6F9B3FCD1C497609592C8E4A1F4F1C0749E3E6B5A6B2D3F8E6D",
+        "PreviousTxnLgrSeq": 5,
+        "SignerEntries": [
+          { "SignerEntry": { "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2 } },
+          { "SignerEntry": { "Account": "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n", "SignerWeight": 1 } }
+        ],
+        "SignerListID": 0,
+        "SignerQuorum": 3,
+        "index": "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+      }
+    ]
+  },
+  "ledger_current_index": 4,
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    let signer_lists = info.account_data.unwrap().signer_lists.unwrap();
+    assert_eq!(signer_lists[0].SignerQuorum, 3);
+    let weights: Vec<u16> = signer_lists[0]
+        .SignerEntries
+        .iter()
+        .map(|entry| entry.SignerEntry.SignerWeight)
+        .collect();
+    assert_eq!(weights, vec![2, 1]);
+}
+
+#[test]
+fn json_expanded_ledger_offer_create_test() {
+    let ledger: LedgerInfo = serde_json::from_str(
+        r#"{
+  "ledger": {
+    "closed": true,
+    "ledger_index": "56865245",
+    "parent_hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+    "seqNum": "56865245",
+    "transactions": [
+      {
+        "Account": "rBTwLga3i2gz3doX6Gva3MgEV8ZCD8jjah",
+        "Expiration": 595640108,
+        "Fee": "10",
+        "Flags": 524288,
+        "OfferSequence": 1752791,
+        "Sequence": 1752792,
+        "SigningPubKey": "03EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3",
+        "TakerGets": "15000000000",
+        "TakerPays": {
+          "currency": "CNY",
+          "issuer": "rKiCet8SdvWxPXnAgYarFUXMh1zCPz432Y",
+          "value": "20160.75"
+        },
+        "TransactionType": "OfferCreate",
+        "TxnSignature": "3045022100A5023A0E64923616FCDB6D664F569644C7C9D1895772F986CD6B981B515B02A00220530C973E9A8395BC6FE2484948D2751F6B030FC7FB8575D1BFB406368AD554D9",
+        "hash": "0F7ED9F40742D8A513AE86029462B7A6768325583DF8EE21B7EC663019DD6A0F",
+        "metaData": {
+          "AffectedNodes": [
+            {
+              "CreatedNode": {
+                "LedgerEntryType": "Offer",
+                "LedgerIndex": "6004A8E8AD19A6F4C8A2080F1F1C8F41D9E2E56A6E0112B06A1A1E0B1EFD1A3B",
+                "NewFields": {
+                  "Account": "rBTwLga3i2gz3doX6Gva3MgEV8ZCD8jjah",
+                  "Sequence": 1752792
+                }
+              }
+            }
+          ],
+          "TransactionIndex": 3,
+          "TransactionResult": "tesSUCCESS"
+        }
+      }
+    ]
+  },
+  "ledger_hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+  "ledger_index": 56865245,
+  "status": "success",
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    let offer = &ledger.ledger.unwrap().transactions.unwrap()[0];
+    assert_eq!(offer.TransactionType, TransactionType::OfferCreate);
+    assert_eq!(offer.OfferSequence, Some(1_752_791));
+    assert_eq!(
+        offer.TakerGets.as_ref().and_then(Balance::as_xrp),
+        Some(BigDecimal::from(15_000))
+    );
+    match offer.TakerPays {
+        Some(Balance::Other { ref value, .. }) => {
+            assert_eq!(value, &BigDecimal::from_str("20160.75").unwrap())
+        }
+        ref other => panic!("expected CNY, got {:?}", other),
+    }
+}
+
+#[test]
+fn json_account_tx_binary_test() {
+    let account_tx: AccountTx = serde_json::from_str(
+        r#"{
+  "account": "rLNaPoKeeBjZe2qs6x52yVPZpZ8td4dc6w",
+  "ledger_index_max": 56865245,
+  "ledger_index_min": 32570,
+  "limit": 1,
+  "marker": { "ledger": 56865229, "seq": 11 },
+  "status": "success",
+  "transactions": [
+    {
+      "ledger_index": 56865245,
+      "meta": "201C00000000F8E5110061250003BE1B55DB730E8E1F6D4177BE2B5927F5EACC8E2B25B78E3B52A1AE195D5C70F5850D65624000000002C3C9AB",
+      "tx_blob": "1200002280000000240000000161400000000098968068400000000000000A732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+      "validated": true
+    }
+  ]
+}"#,
+    )
+    .unwrap();
+    let entry = account_tx.transactions[0].as_binary().unwrap();
+    assert_eq!(entry.ledger_index, 56_865_245);
+    assert!(entry.tx_blob.starts_with("120000"));
+    assert!(account_tx.transactions[0].as_json().is_none());
+
+    let entry: AccountTxEntry = serde_json::from_str(
+        r#"{
+  "meta": { "TransactionIndex": 0, "TransactionResult": "tesSUCCESS" },
+  "tx": { "ledger_index": 56865245 },
+  "validated": true
+}"#,
+    )
+    .unwrap();
+    assert!(entry.as_json().is_some());
+}
+
+#[test]
+fn json_manifest_test() {
+    let manifest: Manifest = serde_json::from_str(
+        r#"{
+  "details": {
+    "domain": "",
+    "ephemeral_key": "n9KQ2DVL7QhgovChk81W8idxm7wDsYzXuiGzCHgJ8kNuqBVdPLNj",
+    "master_key": "nHUFE9prPXPrHcG3SkwP1UzAQbSphqyQkQK9ATXLZsfkezhhda3p",
+    "seq": 1
+  },
+  "manifest": "JAAAAAFxIe3AkJgOyqs3y+UuiZI27Ff5",
+  "requested": "nHUFE9prPXPrHcG3SkwP1UzAQbSphqyQkQK9ATXLZsfkezhhda3p",
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(manifest.details.unwrap().seq, 1);
+
+    let unknown: Manifest = serde_json::from_str(
+        r#"{
+  "requested": "nHBtDzdRDykxiuv7uSMPTcGexNm879RUUz5GW4h1qgjbtyvWZ1LE",
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert!(unknown.details.is_none());
+    assert!(unknown.manifest.is_none());
+}
+
+#[test]
+fn json_ping_test() {
+    for result in &["{}", r#"{ "status": "success" }"#] {
+        let ping: XrpResponse<Ping> = serde_json::from_str(result).unwrap();
+        assert!(ping.into_result().is_ok());
+    }
+}
+
+#[test]
+fn json_random_test() {
+    let random: Random = serde_json::from_str(
+        r#"{
+  "random": "8ED765AEBBD6767603C2C9375B2679AEC76E6A8133EF59F04F9FC1AAA70E41AF",
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(hex::decode(&random.random).unwrap().len(), 32);
+}
+
+#[test]
+fn offer_quality_ratio_test() {
+    let offer: Offer = serde_json::from_str(
+        r#"{
+  "Account": "rBTwLga3i2gz3doX6Gva3MgEV8ZCD8jjah",
+  "BookDirectory": "DFA3B6DDAB58C7E8E5D944E736DA4B7046C30E4F460FD9DE4E1566CB2231A001",
+  "Sequence": 1752792,
+  "TakerGets": "15000000000",
+  "TakerPays": {
+    "currency": "USD",
+    "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    "value": "20160.75"
+  },
+  "quality": "0.00000134405"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        offer.quality_ratio(),
+        Some(BigDecimal::from_str("1.34405").unwrap())
+    );
+
+    let account_offer: AccountOffer = serde_json::from_str(
+        r#"{
+  "flags": 0,
+  "quality": "1500000",
+  "seq": 5,
+  "taker_gets": {
+    "currency": "USD",
+    "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    "value": "2"
+  },
+  "taker_pays": "3000000"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        account_offer.quality_ratio(),
+        Some(BigDecimal::from_str("1.5").unwrap())
+    );
+}
+
+#[test]
+fn ledger_index_as_u64_test() {
+    let current: LedgerIndex =
+        serde_json::from_str(r#"{ "ledger_current_index": 56865245 }"#).unwrap();
+    assert_eq!(current.as_u64(), Some(56_865_245));
+    let number: LedgerIndex = serde_json::from_str(r#"{ "ledger_index": 56865245 }"#).unwrap();
+    assert_eq!(number.as_u64(), Some(56_865_245));
+    let string: LedgerIndex = serde_json::from_str(r#"{ "ledger_index": "56865245" }"#).unwrap();
+    assert_eq!(string.as_u64(), Some(56_865_245));
+    let validated: LedgerIndex =
+        serde_json::from_str(r#"{ "ledger_index": "validated" }"#).unwrap();
+    assert_eq!(validated.as_u64(), None);
+}
+
+#[test]
+fn json_tx_history_test() {
+    let history: TxHistory = serde_json::from_str(
+        r#"{
+  "index": 0,
+  "status": "success",
+  "txs": [
+    {
+      "Account": "r9bf8V4ZN8KNJmNU8aRCJm8unVgL2fVXw1",
+      "Fee": "12",
+      "Flags": 2147483648,
+      "LastLedgerSequence": 56865235,
+      "OfferSequence": 1423459,
+      "Sequence": 1423461,
+      "SigningPubKey": "03CF4F7A7A23DCA7A3E1E947B2F7209E6E3F11D2C1A4A1C6A1E3D9EEC1C3AA99A9",
+      "TransactionType": "OfferCancel",
+      "TxnSignature": "3044022023BD3A1E8D99FD2A4D8D1D53B2A18A0B6235F1E8F5C8E44B0C0D2966F0FB77BB02201EDF6B0B2A5F5F30C2A8C7D14DBF6EE0C1F1BD3838AC30DB71F6E4B2C93D0C63",
+      "hash": "AB8DFCD6C2F8BB88A6F22B0C2A7D1DD6D8D215BA73D30D99C8A6282D5EAD85FB",
+      "ledger_index": 56865233
+    }
+  ]
+}"#,
+    )
+    .unwrap();
+    assert_eq!(history.index, 0);
+    assert_eq!(history.txs[0].TransactionType, TransactionType::OfferCancel);
+    assert_eq!(history.txs[0].OfferSequence, Some(1_423_459));
+
+    let not_implemented: XrpResponse<TxHistory> = serde_json::from_str(
+        r#"{
+  "error": "notImpl",
+  "error_code": 74,
+  "error_message": "Not implemented.",
+  "status": "error"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        not_implemented.into_result().unwrap_err().to_string(),
+        "notImpl: Not implemented."
+    );
+}
+
+#[test]
+fn request_ledger_index_from_reply_test() {
+    let reply: LedgerIndex =
+        serde_json::from_str(r#"{ "ledger_current_index": 56865245 }"#).unwrap();
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let params = AccountInfoParams {
+        account: &account,
+        strict: true,
+        ledger_index: RequestLedgerIndex::from(&reply),
+        queue: false,
+        signer_lists: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "strict": true,
+            "ledger_index": 56865245,
+            "queue": false
+        })
+    );
+    let validated: LedgerIndex =
+        serde_json::from_str(r#"{ "ledger_index": "validated" }"#).unwrap();
+    assert_eq!(
+        serde_json::to_value(RequestLedgerIndex::from(validated)).unwrap(),
+        serde_json::json!({ "ledger_index": "validated" })
+    );
+}
+
+#[test]
+fn json_feature_test() {
+    let all: FeatureInfo = serde_json::from_str(
+        r#"{
+  "features": {
+    "42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE": {
+      "enabled": false,
+      "name": "FeeEscalation",
+      "supported": true,
+      "vetoed": false
+    },
+    "4C97EBA926031A7CF7D7B36FDE3ED66DDA5421192D63DE53FFB46E43B9DC8373": {
+      "enabled": true,
+      "name": "MultiSign",
+      "supported": true,
+      "vetoed": false
+    }
+  },
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(all.features.len(), 2);
+    let multi_sign = "4C97EBA926031A7CF7D7B36FDE3ED66DDA5421192D63DE53FFB46E43B9DC8373";
+    assert!(all.features[multi_sign].enabled);
+
+    let single: FeatureInfo = serde_json::from_str(
+        r#"{
+  "4C97EBA926031A7CF7D7B36FDE3ED66DDA5421192D63DE53FFB46E43B9DC8373": {
+    "enabled": true,
+    "name": "MultiSign",
+    "supported": true,
+    "vetoed": false
+  },
+  "status": "success"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(single.features.len(), 1);
+    assert_eq!(single.features[multi_sign].name, "MultiSign");
+}
+
+#[test]
+fn json_get_aggregate_price_test() {
+    let params = GetAggregatePriceParams {
+        base_asset: "XRP".into(),
+        quote_asset: "USD".into(),
+        oracles: vec![OracleRef {
+            account: "rp047ow9WcPmnNpVHMQV5A4BF6vaL9Abm6".into(),
+            oracle_document_id: 34,
+        }],
+        trim: Some(20),
+        time_threshold: None,
+        ledger_index: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "base_asset": "XRP",
+            "quote_asset": "USD",
+            "oracles": [{
+                "account": "rp047ow9WcPmnNpVHMQV5A4BF6vaL9Abm6",
+                "oracle_document_id": 34
+            }],
+            "trim": 20
+        })
+    );
+
+    let price: AggregatePrice = serde_json::from_str(
+        r#"{
+  "entire_set": {
+    "mean": "74.75",
+    "size": 10,
+    "standard_deviation": "0.1290994448735806"
+  },
+  "ledger_current_index": 25,
+  "median": "74.75",
+  "status": "success",
+  "trimmed_set": {
+    "mean": "74.75",
+    "size": 6,
+    "standard_deviation": "0.1290994448735806"
+  },
+  "time": 78937648,
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    assert_eq!(price.median, BigDecimal::from_str("74.75").unwrap());
+    assert_eq!(price.entire_set.size, 10);
+    assert_eq!(price.trimmed_set.unwrap().size, 6);
+    assert_eq!(price.ledger_index.as_u64(), Some(25));
+}
+
+#[test]
+fn amm_info_params_test() {
+    let issuer: Account = "rhpHaFggC92ELty3n3yDEtuFgWxXWkUFET".parse().unwrap();
+    let params = AmmInfoParams::new(
+        None,
+        Some((BookAsset::xrp(), BookAsset::issued("USD", &issuer))),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "asset": { "currency": "XRP" },
+            "asset2": { "currency": "USD", "issuer": "rhpHaFggC92ELty3n3yDEtuFgWxXWkUFET" }
+        })
+    );
+    let amm_account: Account = "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM".parse().unwrap();
+    assert!(AmmInfoParams::new(Some(&amm_account), None, None).is_ok());
+    assert!(AmmInfoParams::new(
+        Some(&amm_account),
+        Some((BookAsset::xrp(), BookAsset::issued("USD", &issuer))),
+        None
+    )
+    .is_err());
+    assert!(AmmInfoParams::new(None, None, None).is_err());
+}
+
+#[test]
+fn json_amm_info_test() {
+    let amm_info: AmmInfo = serde_json::from_str(
+        r#"{
+  "amm": {
+    "account": "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM",
+    "amount": "791579",
+    "amount2": {
+      "currency": "USD",
+      "issuer": "rhpHaFggC92ELty3n3yDEtuFgWxXWkUFET",
+      "value": "25.57994245836062"
+    },
+    "asset2_frozen": false,
+    "auction_slot": {
+      "account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+      "auth_accounts": [{ "account": "r3f2WpQMsAd8k4Zoijv2PZ78EYFJ2EdvgV" }],
+      "discounted_fee": 0,
+      "expiration": "2024-04-25T18:42:31+0000",
+      "price": {
+        "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+        "issuer": "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM",
+        "value": "0"
+      },
+      "time_interval": 0
+    },
+    "lp_token": {
+      "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+      "issuer": "rp9E3FN3gNmvePGhYnf414T2TkUuoxu8vM",
+      "value": "4405.074299522358"
+    },
+    "trading_fee": 500,
+    "vote_slots": [
+      {
+        "account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+        "trading_fee": 500,
+        "vote_weight": 100000
+      }
+    ]
+  },
+  "ledger_current_index": 8013919,
+  "status": "success",
+  "validated": false
+}"#,
+    )
+    .unwrap();
+    let amm = amm_info.amm;
+    assert_eq!(amm.trading_fee, 500);
+    assert_eq!(
+        amm.amount.as_xrp(),
+        Some(BigDecimal::from_str("0.791579").unwrap())
+    );
+    assert_eq!(amm.vote_slots.unwrap()[0].vote_weight, 100_000);
+    assert_eq!(amm.auction_slot.unwrap().auth_accounts.unwrap().len(), 1);
 }
 
 #[test]
 fn json_ledger_test() {
     #[derive(Deserialize)]
+    #[allow(dead_code)]
     struct RpcResponse<T> {
         pub result: Option<T>,
         pub error: Option<serde_json::Value>,
@@ -331,3 +4116,38 @@ fn json_ledger_test() {
     )
     .unwrap();
 }
+
+#[test]
+fn json_ledger_pseudo_transaction_test() {
+    let ledger: NestedLedgerInfo = serde_json::from_str(
+        r#"{
+  "accepted": true,
+  "closed": true,
+  "ledger_hash": "30BC3B59A2DCB4BC402637A1DEE3F22C6AC4D09E2CDFCAE8C84F11D7E6E251F5",
+  "ledger_index": "38129",
+  "parent_hash": "197B5016B33A79CECA4AA704B534D5999A9674FAD9CBDD82309835D7A784A35F",
+  "seqNum": "38129",
+  "transactions": [
+    {
+      "BaseFee": "000000000000000A",
+      "LedgerSequence": 38128,
+      "ReferenceFeeUnits": 10,
+      "ReserveBase": 50000000,
+      "ReserveIncrement": 12500000,
+      "TransactionType": "SetFee",
+      "hash": "5B14F17FF8E9D9F3A2B0C3D0D24F8F1E2A05D10C3C6EFA3C1A0F17BDB3B5F6E1"
+    }
+  ]
+}"#,
+    )
+    .unwrap();
+    let set_fee = &ledger.transactions.unwrap()[0];
+    assert_eq!(set_fee.TransactionType, TransactionType::SetFee);
+    assert!(set_fee.TransactionType.is_pseudo());
+    assert_eq!(set_fee.Account, None);
+    assert_eq!(set_fee.Fee, None);
+    assert_eq!(set_fee.Sequence, None);
+    assert_eq!(set_fee.BaseFee.as_ref().unwrap(), "000000000000000A");
+    assert_eq!(set_fee.ReserveBase, Some(50_000_000));
+    assert_eq!(set_fee.LedgerSequence, Some(38_128));
+}
@@ -0,0 +1,128 @@
+use crate::XrpError;
+use failure::Error;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Upper bounds of the latency histogram buckets, slower calls land in one extra bucket.
+pub const LATENCY_BUCKETS_MS: &[u64] = &[10, 50, 100, 250, 500, 1000, 2500, 5000];
+
+/**
+ * Told about every call of a client built with `XRPClientBuilder::metrics`, retries
+ * included in its latency, and every call `measured` wraps. `error_code` is rippled's
+ * error token, or `transport` for errors rippled did not send.
+ */
+pub trait Metrics {
+    fn record(&self, method: &str, latency: Duration, error_code: Option<&str>);
+}
+
+/// rippled's error token, or `transport` for an error rippled did not send.
+pub(crate) fn error_code(error: &Error) -> &str {
+    error
+        .downcast_ref::<XrpError>()
+        .map_or("transport", XrpError::code)
+}
+
+/// Any other call wrapped as `call`, e.g. a `FailoverClient` one, `method` being the rpc name.
+pub fn measured<T, M, F>(metrics: &M, method: &str, call: F) -> Result<T, Error>
+where
+    M: Metrics + ?Sized,
+    F: FnOnce() -> Result<T, Error>,
+{
+    let start = Instant::now();
+    let result = call();
+    metrics.record(
+        method,
+        start.elapsed(),
+        result.as_ref().err().map(error_code),
+    );
+    result
+}
+
+/// The counters of `AtomicMetrics` at one point in time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub requests: u64,
+    /// Calls by rpc method name.
+    pub methods: HashMap<String, u64>,
+    pub errors: HashMap<String, u64>,
+    /// One count per `LATENCY_BUCKETS_MS` entry, plus the calls slower than all of them.
+    pub latency_buckets: Vec<u64>,
+}
+
+/// Counts calls, by method too, errors by code and latencies, for exporting to Prometheus and the like.
+#[derive(Debug)]
+pub struct AtomicMetrics {
+    requests: AtomicU64,
+    methods: Mutex<HashMap<String, u64>>,
+    errors: Mutex<HashMap<String, u64>>,
+    latency_buckets: Vec<AtomicU64>,
+}
+
+impl AtomicMetrics {
+    pub fn new() -> Self {
+        AtomicMetrics {
+            requests: AtomicU64::new(0),
+            methods: Mutex::new(HashMap::new()),
+            errors: Mutex::new(HashMap::new()),
+            latency_buckets: (0..=LATENCY_BUCKETS_MS.len())
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+        }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            requests: self.requests.load(Ordering::Relaxed),
+            methods: self.methods.lock().unwrap().clone(),
+            errors: self.errors.lock().unwrap().clone(),
+            latency_buckets: self
+                .latency_buckets
+                .iter()
+                .map(|bucket| bucket.load(Ordering::Relaxed))
+                .collect(),
+        }
+    }
+}
+
+impl Default for AtomicMetrics {
+    fn default() -> Self {
+        AtomicMetrics::new()
+    }
+}
+
+impl Metrics for AtomicMetrics {
+    fn record(&self, method: &str, latency: Duration, error_code: Option<&str>) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        *self
+            .methods
+            .lock()
+            .unwrap()
+            .entry(method.into())
+            .or_insert(0) += 1;
+        if let Some(code) = error_code {
+            *self.errors.lock().unwrap().entry(code.into()).or_insert(0) += 1;
+        }
+        let latency_ms = latency.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|&bound| latency_ms <= bound)
+            .unwrap_or_else(|| LATENCY_BUCKETS_MS.len());
+        self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn atomic_metrics_buckets_test() {
+    let metrics = AtomicMetrics::new();
+    metrics.record("fee", Duration::from_millis(5), None);
+    metrics.record("fee", Duration::from_millis(300), Some("tooBusy"));
+    metrics.record("ledger", Duration::from_secs(60), Some("tooBusy"));
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.requests, 3);
+    assert_eq!(snapshot.methods["fee"], 2);
+    assert_eq!(snapshot.methods["ledger"], 1);
+    assert_eq!(snapshot.errors["tooBusy"], 2);
+    assert_eq!(snapshot.latency_buckets, vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+}
@@ -0,0 +1,289 @@
+use crate::XRPClient;
+use serde_json::json;
+use serde_json::value::Value;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+struct Responses {
+    queued: HashMap<String, VecDeque<Value>>,
+    requests: Vec<Value>,
+    headers: Vec<Vec<(String, String)>>,
+    gzip: bool,
+    gzipped: usize,
+    connections: usize,
+    delays: HashMap<String, Duration>,
+}
+
+impl Responses {
+    /// The last response registered for a method keeps being served once the others are used up.
+    fn next(&mut self, method: &str) -> Value {
+        match self.queued.get_mut(method) {
+            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+            Some(queue) if !queue.is_empty() => queue[0].clone(),
+            _ => json!({
+                "error": "unknownCmd",
+                "error_code": 32,
+                "error_message": format!("No mock response registered for {}", method),
+                "status": "error",
+            }),
+        }
+    }
+
+    /// How long the reply to `request` is held back, the longest delay of its calls for a batch.
+    fn delay(&self, request: &Value) -> Duration {
+        let calls = match request {
+            Value::Array(calls) => calls.iter().collect(),
+            call => vec![call],
+        };
+        calls
+            .into_iter()
+            .filter_map(|call| self.delays.get(call["method"].as_str()?))
+            .max()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Batch arrays are recorded and answered call by call.
+    fn answer(&mut self, call: Value) -> Value {
+        let result = self.next(call["method"].as_str().unwrap_or_default());
+        let reply = json!({
+            "id": call["id"],
+            "jsonrpc": "2.0",
+            "result": result,
+        });
+        self.requests.push(call);
+        reply
+    }
+}
+
+/**
+ * A local json rpc endpoint answering with canned `result` objects, keyed by method name.
+ * Methods without a response get an `unknownCmd` error, like rippled does.
+ */
+#[derive(Debug, Clone)]
+pub struct MockTransport {
+    url: String,
+    responses: Arc<Mutex<Responses>>,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Binding the mock transport");
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let responses = Arc::new(Mutex::new(Responses::default()));
+        let serving = responses.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                serving.lock().unwrap().connections += 1;
+                let serving = serving.clone();
+                thread::spawn(move || serve(stream, &serving));
+            }
+        });
+        MockTransport { url, responses }
+    }
+
+    /// Queues `result` for the next `method` call, repeated calls are answered in registration order.
+    pub fn respond(&self, method: &str, result: Value) -> &Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .queued
+            .entry(method.into())
+            .or_default()
+            .push_back(result);
+        self
+    }
+
+    /// Holds every reply to `method` back for `delay`, e.g. to make a call time out.
+    pub fn delay(&self, method: &str, delay: Duration) -> &Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .delays
+            .insert(method.into(), delay);
+        self
+    }
+
+    /// Compress replies to requests that accept gzip.
+    pub fn gzip(&self, enabled: bool) -> &Self {
+        self.responses.lock().unwrap().gzip = enabled;
+        self
+    }
+
+    /// How many replies went out compressed.
+    pub fn gzipped(&self) -> usize {
+        self.responses.lock().unwrap().gzipped
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Every request received so far, as the parsed json rpc body.
+    pub fn requests(&self) -> Vec<Value> {
+        self.responses.lock().unwrap().requests.clone()
+    }
+
+    /// How many tcp connections clients opened, calls on a kept alive one do not add up.
+    pub fn connections(&self) -> usize {
+        self.responses.lock().unwrap().connections
+    }
+
+    /// The headers of every http request so far, names lowercased. A batch is one request.
+    pub fn request_headers(&self) -> Vec<Vec<(String, String)>> {
+        self.responses.lock().unwrap().headers.clone()
+    }
+}
+
+impl Default for MockTransport {
+    fn default() -> Self {
+        MockTransport::new()
+    }
+}
+
+impl XRPClient {
+    /// An unthrottled client talking to `transport`, use `builder().url(transport.url())` for a throttled one.
+    pub fn with_transport(transport: &MockTransport) -> XRPClient {
+        XRPClient::new(transport.url.clone(), None, None, 0, 0.0, 0)
+    }
+}
+
+/// Answers requests on one kept alive connection until the client closes it.
+fn serve(stream: TcpStream, responses: &Mutex<Responses>) {
+    let mut reader = BufReader::new(&stream);
+    while let Some((request, headers)) = read_request(&mut reader) {
+        let accepts_gzip = headers
+            .iter()
+            .any(|(name, value)| name == "accept-encoding" && value.contains("gzip"));
+        let (body, encoding, delay) = {
+            let mut responses = responses.lock().unwrap();
+            responses.headers.push(headers);
+            let delay = responses.delay(&request);
+            let body = match request {
+                Value::Array(calls) => Value::Array(
+                    calls
+                        .into_iter()
+                        .map(|call| responses.answer(call))
+                        .collect(),
+                ),
+                call => responses.answer(call),
+            }
+            .to_string()
+            .into_bytes();
+            if responses.gzip && accepts_gzip {
+                responses.gzipped += 1;
+                (gzip(&body), "Content-Encoding: gzip\r\n", delay)
+            } else {
+                (body, "", delay)
+            }
+        };
+        thread::sleep(delay);
+        let mut writer = &stream;
+        let written = write!(
+            writer,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\n\r\n",
+            encoding,
+            body.len()
+        )
+        .and_then(|_| writer.write_all(&body));
+        if written.is_err() {
+            return;
+        }
+    }
+}
+
+/// The parsed body and the headers, names lowercased.
+fn read_request(reader: &mut impl BufRead) -> Option<(Value, Vec<(String, String)>)> {
+    let mut content_length = 0;
+    let mut headers = Vec::new();
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).ok()? == 0 {
+        return None;
+    }
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let colon = line.find(':')?;
+        let (name, value) = (line[..colon].to_ascii_lowercase(), line[colon + 1..].trim());
+        if name == "content-length" {
+            content_length = value.parse().ok()?;
+        }
+        headers.push((name, value.to_owned()));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+    Some((serde_json::from_slice(&body).ok()?, headers))
+}
+
+/// A gzip member made of stored deflate blocks, valid without compressing anything.
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+    let mut chunks: Vec<&[u8]> = data.chunks(0xffff).collect();
+    if chunks.is_empty() {
+        chunks.push(&[]);
+    }
+    let last = chunks.len() - 1;
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        let len = chunk.len() as u16;
+        out.push(if index == last { 1 } else { 0 });
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(chunk);
+    }
+    out.extend_from_slice(&crc32(data).to_le_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[test]
+fn mock_responses_in_order_test() {
+    let mut responses = Responses::default();
+    responses
+        .queued
+        .entry("ledger_current".into())
+        .or_default()
+        .extend(vec![
+            json!({ "ledger_current_index": 1 }),
+            json!({ "ledger_current_index": 2 }),
+        ]);
+    assert_eq!(responses.next("ledger_current")["ledger_current_index"], 1);
+    assert_eq!(responses.next("ledger_current")["ledger_current_index"], 2);
+    assert_eq!(responses.next("ledger_current")["ledger_current_index"], 2);
+    assert_eq!(responses.next("fee")["error"], "unknownCmd");
+}
+
+#[test]
+fn mock_crc32_test() {
+    assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(gzip(b"").len(), 10 + 5 + 8);
+}
@@ -0,0 +1,60 @@
+use crate::{NftOffers, NftOffersParams, XRPClient, XrpError};
+use failure::Error;
+
+/// rippled answers `objectNotFound` rather than an empty list for an NFT nobody made offers on.
+fn or_empty(nft_id: String, offers: Result<NftOffers, Error>) -> Result<NftOffers, Error> {
+    match offers {
+        Err(ref error)
+            if error.downcast_ref::<XrpError>().map(XrpError::code) == Some("objectNotFound") =>
+        {
+            Ok(NftOffers {
+                nft_id,
+                offers: Vec::new(),
+                limit: None,
+                marker: None,
+            })
+        }
+        offers => offers,
+    }
+}
+
+impl XRPClient {
+    /// `nft_buy_offers`, with no offers instead of `objectNotFound`.
+    pub fn nft_buy_offers_or_empty(&self, params: NftOffersParams) -> Result<NftOffers, Error> {
+        let nft_id = params.nft_id.clone();
+        or_empty(nft_id, self.nft_buy_offers(params))
+    }
+
+    /// `nft_sell_offers`, with no offers instead of `objectNotFound`.
+    pub fn nft_sell_offers_or_empty(&self, params: NftOffersParams) -> Result<NftOffers, Error> {
+        let nft_id = params.nft_id.clone();
+        or_empty(nft_id, self.nft_sell_offers(params))
+    }
+}
+
+#[test]
+fn nft_offers_not_found_test() {
+    let nft_id = "00090000D0B007439B080E9B05BF62403911301A7B1F0CFAA048C0A200000007";
+    let response: crate::XrpResponse<NftOffers> = serde_json::from_str(
+        r#"{
+  "error": "objectNotFound",
+  "error_code": 92,
+  "error_message": "The requested object was not found.",
+  "status": "error"
+}"#,
+    )
+    .unwrap();
+    let offers = or_empty(nft_id.into(), response.into_result()).unwrap();
+    assert_eq!(offers.nft_id, nft_id);
+    assert!(offers.offers.is_empty());
+
+    let response: crate::XrpResponse<NftOffers> =
+        serde_json::from_str(r#"{ "error": "invalidParams", "status": "error" }"#).unwrap();
+    let error = or_empty(nft_id.into(), response.into_result()).unwrap_err();
+    assert_eq!(
+        error
+            .downcast_ref::<crate::XrpError>()
+            .map(crate::XrpError::code),
+        Some("invalidParams")
+    );
+}
@@ -0,0 +1,214 @@
+use crate::{
+    Account, AccountLinesParams, AccountTxEntry, AccountTxParams, LedgerDataParams, LedgerInfo,
+    LedgerInfoParams, RequestLedgerIndex, TransactionType, TrustLine, XRPClient,
+};
+use failure::{format_err, Error};
+
+/**
+ * Walks a marker based listing one page at a time. [1]
+ * `fetch` receives the marker for the next page (`None` for the first one) and
+ * returns the page items plus the marker the server handed back.
+ * 1: https://xrpl.org/markers-and-pagination.html
+ */
+pub(crate) struct Paginated<T, F> {
+    fetch: F,
+    page: std::vec::IntoIter<T>,
+    marker: Option<serde_json::Value>,
+    seen_markers: Vec<serde_json::Value>,
+    error: Option<Error>,
+    finished: bool,
+}
+
+impl<T, F> Paginated<T, F>
+where
+    F: FnMut(Option<serde_json::Value>) -> Result<(Vec<T>, Option<serde_json::Value>), Error>,
+{
+    pub(crate) fn new(fetch: F) -> Self {
+        Paginated {
+            fetch,
+            page: Vec::new().into_iter(),
+            marker: None,
+            seen_markers: Vec::new(),
+            error: None,
+            finished: false,
+        }
+    }
+}
+
+impl<T, F> Iterator for Paginated<T, F>
+where
+    F: FnMut(Option<serde_json::Value>) -> Result<(Vec<T>, Option<serde_json::Value>), Error>,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.page.next() {
+                return Some(Ok(item));
+            }
+            if let Some(error) = self.error.take() {
+                return Some(Err(error));
+            }
+            if self.finished {
+                return None;
+            }
+            match (self.fetch)(self.marker.take()) {
+                Ok((items, marker)) => {
+                    self.page = items.into_iter();
+                    match marker {
+                        Some(ref marker) if self.seen_markers.contains(marker) => {
+                            self.finished = true;
+                            self.error =
+                                Some(format_err!("The server returned marker {} twice", marker));
+                        }
+                        Some(marker) => {
+                            self.seen_markers.push(marker.clone());
+                            self.marker = Some(marker);
+                        }
+                        None => self.finished = true,
+                    }
+                }
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+impl XRPClient {
+    /// Every transaction of `account_tx`, requesting the next page whenever one runs out.
+    pub fn account_tx_iter<'a>(
+        &'a self,
+        params: AccountTxParams<'a, 'a>,
+    ) -> impl Iterator<Item = Result<AccountTxEntry, Error>> + 'a {
+        Paginated::new(move |marker| {
+            let mut params = params.clone();
+            params.marker = marker;
+            self.account_tx(params)
+                .map(|page| (page.transactions, page.marker))
+        })
+    }
+
+    /**
+     * `account_tx_iter` keeping only transactions of one of `types`. rippled cannot filter
+     * `account_tx` by type, so every page is still fetched and the rest dropped here.
+     * Errors are passed on.
+     */
+    pub fn account_tx_filtered<'a>(
+        &'a self,
+        params: AccountTxParams<'a, 'a>,
+        types: &'a [TransactionType],
+    ) -> impl Iterator<Item = Result<AccountTxEntry, Error>> + 'a {
+        self.account_tx_iter(params).filter(move |entry| match entry {
+            Ok(entry) => matches!(
+                entry.transaction_type(),
+                Some(transaction_type) if types.contains(&transaction_type)
+            ),
+            Err(_) => true,
+        })
+    }
+
+    /// Every object of a ledger, pin `ledger_index` or `ledger_hash` so all pages come from the same one.
+    pub fn ledger_data_iter<'a>(
+        &'a self,
+        params: LedgerDataParams,
+    ) -> impl Iterator<Item = Result<serde_json::Value, Error>> + 'a {
+        Paginated::new(move |marker| {
+            let mut params = params.clone();
+            params.marker = marker;
+            self.ledger_data(params)
+                .map(|page| (page.state, page.marker))
+        })
+    }
+
+    /**
+     * Ledgers `start` through `end` with their transactions expanded, one `ledger` call
+     * each and through the throttle like any other call. A ledger that fails is an `Err`
+     * item, the ones after it are still fetched.
+     */
+    pub fn ledgers_in_range<'a>(
+        &'a self,
+        start: u64,
+        end: u64,
+    ) -> impl Iterator<Item = Result<LedgerInfo, Error>> + 'a {
+        (start..=end).map(move |ledger_index| {
+            self.ledger(LedgerInfoParams {
+                ledger_hash: None,
+                ledger_index: Some(RequestLedgerIndex::from_u64(ledger_index)),
+                full: None,
+                accounts: None,
+                transactions: Some(true),
+                expand: Some(true),
+                owner_funds: None,
+                binary: None,
+                queue: None,
+            })
+        })
+    }
+
+    /**
+     * All trust lines of `account` in the latest validated ledger, following markers until
+     * the last page. Later pages ask for the ledger the first one came from, so they cannot
+     * straddle a ledger close.
+     * Stops fetching once `max` lines are in, and fails if the server repeats a marker.
+     */
+    pub fn account_lines_all(
+        &self,
+        account: &Account,
+        max: Option<usize>,
+    ) -> Result<Vec<TrustLine>, Error> {
+        let mut ledger_index = RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        };
+        let lines = Paginated::new(|marker| {
+            let params = AccountLinesParams {
+                account,
+                peer: None,
+                ledger_index: Some(ledger_index.clone()),
+                limit: None,
+                marker,
+            };
+            self.account_lines(params).map(|page| {
+                ledger_index = RequestLedgerIndex::from(&page.ledger_index);
+                (page.lines, page.marker)
+            })
+        });
+        lines.take(max.unwrap_or(usize::MAX)).collect()
+    }
+}
+
+#[test]
+fn paginated_two_pages_test() {
+    let mut requested_markers = Vec::new();
+    let items: Vec<u32> = Paginated::new(|marker: Option<serde_json::Value>| {
+        requested_markers.push(marker.clone());
+        Ok(match marker {
+            None => (vec![1, 2], Some(serde_json::json!({ "ledger": 10, "seq": 2 }))),
+            Some(_) => (vec![3], None),
+        })
+    })
+    .collect::<Result<_, _>>()
+    .unwrap();
+    assert_eq!(items, vec![1, 2, 3]);
+    assert_eq!(
+        requested_markers,
+        vec![None, Some(serde_json::json!({ "ledger": 10, "seq": 2 }))]
+    );
+}
+
+#[test]
+fn paginated_repeated_marker_test() {
+    let mut requests = 0;
+    let items: Vec<Result<u32, Error>> = Paginated::new(|_| {
+        requests += 1;
+        Ok((vec![requests], Some(serde_json::json!("same"))))
+    })
+    .take(10)
+    .collect();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].as_ref().unwrap(), &1);
+    assert_eq!(items[1].as_ref().unwrap(), &2);
+    assert!(items[2].is_err());
+}
@@ -0,0 +1,396 @@
+use crate::{
+    Account, AccountInfoParams, AccountTxParams, LedgerInfo, LedgerInfoParams, RequestLedgerIndex,
+    XRPClient,
+};
+use failure::{format_err, Error};
+use std::str::FromStr;
+
+/**
+ * `strict` starts out `true`, so rippled only takes an address and never reads a seed or
+ * public key as the account, and `queue` `false`. The ledger defaults to `"validated"`. [1]
+ * 1: https://xrpl.org/account_info.html#request-format
+ */
+#[derive(Debug, Clone)]
+pub struct AccountInfoParamsBuilder<'a> {
+    params: AccountInfoParams<'a>,
+}
+
+impl<'a> AccountInfoParams<'a> {
+    pub fn builder(account: &'a Account) -> AccountInfoParamsBuilder<'a> {
+        AccountInfoParamsBuilder {
+            params: AccountInfoParams {
+                account,
+                strict: true,
+                ledger_index: RequestLedgerIndex::StrValue {
+                    ledger_index: "validated".into(),
+                },
+                queue: false,
+                signer_lists: None,
+            },
+        }
+    }
+}
+
+impl<'a> AccountInfoParamsBuilder<'a> {
+    /// Only turn this off to look an account up by its public key on purpose.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.params.strict = strict;
+        self
+    }
+
+    pub fn ledger_index(mut self, ledger_index: RequestLedgerIndex) -> Self {
+        self.params.ledger_index = ledger_index;
+        self
+    }
+
+    /// Queued transactions of the account, rippled only has them for the `"current"` ledger.
+    pub fn queue(mut self, queue: bool) -> Self {
+        self.params.queue = queue;
+        self
+    }
+
+    pub fn signer_lists(mut self, signer_lists: bool) -> Self {
+        self.params.signer_lists = Some(signer_lists);
+        self
+    }
+
+    pub fn build(self) -> AccountInfoParams<'a> {
+        self.params
+    }
+}
+
+/**
+ * Picks the ledgers `account_tx` searches either as a range or as one ledger, never both. [1]
+ * `-1` for either end of the range means the oldest or newest ledger the server has.
+ * 1: https://xrpl.org/account_tx.html#request-format
+ */
+#[derive(Debug, Clone)]
+pub struct AccountTxParamsBuilder<'a, 'b> {
+    params: AccountTxParams<'a, 'b>,
+}
+
+impl<'a, 'b> AccountTxParams<'a, 'b> {
+    pub fn builder(account: &'a Account) -> AccountTxParamsBuilder<'a, 'b> {
+        AccountTxParamsBuilder {
+            params: AccountTxParams {
+                account,
+                ledger_index_min: None,
+                ledger_index_max: None,
+                ledger_hash: None,
+                ledger_index: None,
+                binary: Some(false),
+                forward: Some(false),
+                limit: None,
+                marker: None,
+            },
+        }
+    }
+}
+
+impl<'a, 'b> AccountTxParamsBuilder<'a, 'b> {
+    pub fn ledger_range(mut self, min: i64, max: i64) -> Self {
+        self.params.ledger_index_min = Some(min);
+        self.params.ledger_index_max = Some(max);
+        self
+    }
+
+    pub fn ledger_index(mut self, ledger_index: RequestLedgerIndex) -> Self {
+        self.params.ledger_index = Some(ledger_index);
+        self
+    }
+
+    pub fn ledger_hash(mut self, ledger_hash: &'b str) -> Self {
+        self.params.ledger_hash = Some(ledger_hash);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    /// Oldest transactions first, rippled defaults to newest first.
+    pub fn forward(mut self, forward: bool) -> Self {
+        self.params.forward = Some(forward);
+        self
+    }
+
+    pub fn binary(mut self, binary: bool) -> Self {
+        self.params.binary = Some(binary);
+        self
+    }
+
+    /// Resume from the `marker` of a previous page.
+    pub fn marker(mut self, marker: serde_json::Value) -> Self {
+        self.params.marker = Some(marker);
+        self
+    }
+
+    pub fn build(self) -> Result<AccountTxParams<'a, 'b>, String> {
+        let params = self.params;
+        let range = params.ledger_index_min.is_some() || params.ledger_index_max.is_some();
+        if range && (params.ledger_index.is_some() || params.ledger_hash.is_some()) {
+            return Err("account_tx takes a ledger range or a single ledger, not both".into());
+        }
+        if params.ledger_index.is_some() && params.ledger_hash.is_some() {
+            return Err("account_tx takes either a ledger_index or a ledger_hash".into());
+        }
+        if let (Some(min), Some(max)) = (params.ledger_index_min, params.ledger_index_max) {
+            if min != -1 && max != -1 && min > max {
+                return Err(format!(
+                    "ledger_index_min {} is after ledger_index_max {}",
+                    min, max
+                ));
+            }
+        }
+        Ok(params)
+    }
+}
+
+/**
+ * One ledger, named by a shortcut, its sequence number or its hash. [1]
+ * 1: https://xrpl.org/basic-data-types.html#specifying-ledgers
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum LedgerSpecifier {
+    Validated,
+    Current,
+    Closed,
+    Index(u64),
+    Hash(String),
+}
+
+impl LedgerSpecifier {
+    /// The `ledger_index` field, `None` for `Hash`.
+    pub fn ledger_index(&self) -> Option<RequestLedgerIndex> {
+        let shortcut = |name: &str| {
+            Some(RequestLedgerIndex::StrValue {
+                ledger_index: name.into(),
+            })
+        };
+        match self {
+            LedgerSpecifier::Validated => shortcut("validated"),
+            LedgerSpecifier::Current => shortcut("current"),
+            LedgerSpecifier::Closed => shortcut("closed"),
+            LedgerSpecifier::Index(index) => Some(RequestLedgerIndex::from_u64(*index)),
+            LedgerSpecifier::Hash(_) => None,
+        }
+    }
+
+    /// The `ledger_hash` field, only set for `Hash`.
+    pub fn ledger_hash(&self) -> Option<String> {
+        match self {
+            LedgerSpecifier::Hash(hash) => Some(hash.clone()),
+            _ => None,
+        }
+    }
+
+    /// Points `params` at this ledger, refusing params that already name a ledger.
+    pub fn apply(&self, mut params: LedgerInfoParams) -> Result<LedgerInfoParams, String> {
+        if params.ledger_index.is_some() || params.ledger_hash.is_some() {
+            return Err("The params already set ledger_index or ledger_hash".into());
+        }
+        params.ledger_index = self.ledger_index();
+        params.ledger_hash = self.ledger_hash();
+        Ok(params)
+    }
+}
+
+/// The shortcuts rippled takes for `ledger_index`.
+const LEDGER_INDEX_SHORTCUTS: &[&str] = &["validated", "current", "closed"];
+
+impl RequestLedgerIndex {
+    pub fn from_u64(ledger_index: u64) -> Self {
+        RequestLedgerIndex::Number {
+            ledger_index: ledger_index.into(),
+        }
+    }
+}
+
+/// A shortcut, or a sequence number given as a string, catching typos like `"latest"` early.
+impl FromStr for RequestLedgerIndex {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if LEDGER_INDEX_SHORTCUTS.contains(&s) {
+            return Ok(RequestLedgerIndex::StrValue {
+                ledger_index: s.into(),
+            });
+        }
+        s.parse()
+            .map(RequestLedgerIndex::from_u64)
+            .map_err(|_| {
+                format!(
+                    "{:?} is neither a ledger sequence nor one of {:?}",
+                    s, LEDGER_INDEX_SHORTCUTS
+                )
+            })
+    }
+}
+
+impl XRPClient {
+    /// `ledger` for `specifier`, with the other options taken from `params`.
+    pub fn ledger_by(
+        &self,
+        specifier: LedgerSpecifier,
+        params: LedgerInfoParams,
+    ) -> Result<LedgerInfo, Error> {
+        let params = specifier.apply(params).map_err(|error| format_err!("{}", error))?;
+        self.ledger(params)
+    }
+}
+
+#[test]
+fn account_info_params_builder_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    assert_eq!(
+        serde_json::to_value(AccountInfoParams::builder(&account).build()).unwrap(),
+        serde_json::json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "strict": true,
+            "ledger_index": "validated",
+            "queue": false
+        })
+    );
+    let lenient = AccountInfoParams::builder(&account)
+        .strict(false)
+        .signer_lists(true)
+        .build();
+    assert!(!lenient.strict);
+    assert_eq!(lenient.signer_lists, Some(true));
+}
+
+#[test]
+fn account_tx_params_builder_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let params = AccountTxParams::builder(&account)
+        .ledger_range(-1, 56_865_245)
+        .limit(20)
+        .marker(serde_json::json!({ "ledger": 56865000, "seq": 3 }))
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "ledger_index_min": -1,
+            "ledger_index_max": 56865245,
+            "ledger_hash": null,
+            "binary": false,
+            "forward": false,
+            "limit": 20,
+            "marker": { "ledger": 56865000, "seq": 3 }
+        })
+    );
+}
+
+#[test]
+fn account_tx_params_builder_conflict_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    assert!(AccountTxParams::builder(&account)
+        .ledger_range(-1, -1)
+        .ledger_index(RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        })
+        .build()
+        .is_err());
+    assert!(AccountTxParams::builder(&account)
+        .ledger_hash("31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726")
+        .ledger_index(RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        })
+        .build()
+        .is_err());
+    assert!(AccountTxParams::builder(&account)
+        .ledger_range(20, 10)
+        .build()
+        .is_err());
+}
+
+#[cfg(test)]
+fn ledger_info_params() -> LedgerInfoParams {
+    LedgerInfoParams {
+        ledger_hash: None,
+        ledger_index: None,
+        full: None,
+        accounts: None,
+        transactions: Some(true),
+        expand: None,
+        owner_funds: None,
+        binary: None,
+        queue: None,
+    }
+}
+
+#[test]
+fn ledger_specifier_test() {
+    let serialized = |specifier: LedgerSpecifier| {
+        let params = specifier.apply(ledger_info_params()).unwrap();
+        let value = serde_json::to_value(&params).unwrap();
+        let set: serde_json::Map<_, _> = value
+            .as_object()
+            .unwrap()
+            .iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        serde_json::Value::Object(set)
+    };
+    assert_eq!(
+        serialized(LedgerSpecifier::Validated),
+        serde_json::json!({ "ledger_index": "validated", "transactions": true })
+    );
+    assert_eq!(
+        serialized(LedgerSpecifier::Current),
+        serde_json::json!({ "ledger_index": "current", "transactions": true })
+    );
+    assert_eq!(
+        serialized(LedgerSpecifier::Closed),
+        serde_json::json!({ "ledger_index": "closed", "transactions": true })
+    );
+    assert_eq!(
+        serialized(LedgerSpecifier::Index(56_865_245)),
+        serde_json::json!({ "ledger_index": 56865245, "transactions": true })
+    );
+    assert_eq!(
+        serialized(LedgerSpecifier::Hash(
+            "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726".into()
+        )),
+        serde_json::json!({
+            "ledger_hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+            "transactions": true
+        })
+    );
+
+    let mut named = ledger_info_params();
+    named.ledger_hash =
+        Some("31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726".into());
+    assert!(LedgerSpecifier::Index(1).apply(named).is_err());
+}
+
+#[test]
+fn request_ledger_index_parse_test() {
+    for &shortcut in LEDGER_INDEX_SHORTCUTS {
+        assert_eq!(
+            shortcut.parse::<RequestLedgerIndex>().unwrap(),
+            RequestLedgerIndex::StrValue {
+                ledger_index: shortcut.into(),
+            }
+        );
+    }
+    assert_eq!(
+        "56865245".parse::<RequestLedgerIndex>().unwrap(),
+        RequestLedgerIndex::from_u64(56_865_245)
+    );
+    assert_eq!(
+        serde_json::to_value(RequestLedgerIndex::from_u64(56_865_245)).unwrap(),
+        serde_json::json!({ "ledger_index": 56865245 })
+    );
+    assert!("latest".parse::<RequestLedgerIndex>().is_err());
+    assert!(serde_json::to_value(RequestLedgerIndex::StrValue {
+        ledger_index: "latest".into(),
+    })
+    .is_err());
+    assert!("-1".parse::<RequestLedgerIndex>().is_err());
+    assert!("".parse::<RequestLedgerIndex>().is_err());
+}
@@ -0,0 +1,179 @@
+use crate::flags::PaymentFlags;
+use crate::{Account, Balance, Drops, Memo, MemoWrapper};
+use serde_json::{json, Map, Value};
+
+/**
+ * An unsigned Payment `tx_json` for `sign` or `submit`, built without asking a server.
+ * X-addresses are sent as their classic address, their tags as `SourceTag` and
+ * `DestinationTag`. [1]
+ * 1: https://xrpl.org/payment.html
+ */
+#[derive(Debug, Clone)]
+pub struct PaymentBuilder {
+    account: Account,
+    destination: Account,
+    amount: Balance,
+    destination_tag: Option<u32>,
+    fee: Option<Drops>,
+    sequence: Option<u32>,
+    memos: Vec<MemoWrapper>,
+    flags: PaymentFlags,
+}
+
+impl PaymentBuilder {
+    pub fn new(account: &Account, destination: &Account, amount: Balance) -> Self {
+        PaymentBuilder {
+            account: account.clone(),
+            destination: destination.clone(),
+            amount,
+            destination_tag: None,
+            fee: None,
+            sequence: None,
+            memos: Vec::new(),
+            flags: PaymentFlags::empty(),
+        }
+    }
+
+    pub fn destination_tag(mut self, destination_tag: u32) -> Self {
+        self.destination_tag = Some(destination_tag);
+        self
+    }
+
+    /// Left out, `sign` fills in the current fee.
+    pub fn fee(mut self, fee: Drops) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Left out, `sign` fills in the account's next sequence.
+    pub fn sequence(mut self, sequence: u32) -> Self {
+        self.sequence = Some(sequence);
+        self
+    }
+
+    pub fn memo(mut self, memo: Memo) -> Self {
+        self.memos.push(MemoWrapper { Memo: memo });
+        self
+    }
+
+    pub fn flags(mut self, flags: PaymentFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn build(self) -> Result<Value, String> {
+        match &self.amount {
+            Balance::Other { issuer, .. } if issuer.is_empty() => {
+                return Err("An issued currency amount needs an issuer".into())
+            }
+            Balance::Unavailable => return Err("A payment needs an amount to send".into()),
+            _ => {}
+        }
+        let (account, source_tag) = self.account.to_classic();
+        let (destination, address_tag) = self.destination.to_classic();
+        let destination_tag = match (self.destination_tag, address_tag) {
+            (Some(tag), Some(address_tag)) if tag != address_tag => {
+                return Err(format!(
+                    "Destination tag {} conflicts with tag {} of the X-address",
+                    tag, address_tag
+                ))
+            }
+            (tag, address_tag) => tag.or(address_tag),
+        };
+
+        let mut tx_json = Map::new();
+        tx_json.insert("TransactionType".into(), "Payment".into());
+        tx_json.insert("Account".into(), json!(account));
+        tx_json.insert("Destination".into(), json!(destination));
+        tx_json.insert("Amount".into(), json!(self.amount));
+        if let Some(tag) = source_tag {
+            tx_json.insert("SourceTag".into(), tag.into());
+        }
+        if let Some(tag) = destination_tag {
+            tx_json.insert("DestinationTag".into(), tag.into());
+        }
+        if let Some(fee) = self.fee {
+            tx_json.insert("Fee".into(), json!(fee));
+        }
+        if let Some(sequence) = self.sequence {
+            tx_json.insert("Sequence".into(), sequence.into());
+        }
+        if !self.memos.is_empty() {
+            tx_json.insert("Memos".into(), json!(self.memos));
+        }
+        if !self.flags.is_empty() {
+            tx_json.insert("Flags".into(), self.flags.bits().into());
+        }
+        Ok(Value::Object(tx_json))
+    }
+}
+
+#[test]
+fn payment_builder_xrp_test() {
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let destination: Account = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".parse().unwrap();
+    let tx_json = PaymentBuilder::new(&account, &destination, Balance::XRP(Drops(1_000_000)))
+        .destination_tag(13)
+        .fee(Drops(12))
+        .sequence(2)
+        .memo(Memo {
+            MemoData: Some(hex::encode_upper("rent")),
+            MemoFormat: None,
+            MemoType: Some(hex::encode_upper("invoice")),
+        })
+        .build()
+        .unwrap();
+    assert_eq!(
+        tx_json,
+        json!({
+            "TransactionType": "Payment",
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Amount": "1000000",
+            "DestinationTag": 13,
+            "Fee": "12",
+            "Sequence": 2,
+            "Memos": [{ "Memo": { "MemoData": "72656E74", "MemoType": "696E766F696365" } }]
+        })
+    );
+}
+
+#[test]
+fn payment_builder_issued_test() {
+    use std::str::FromStr;
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let destination = Account::from_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    let usd = |issuer: &str| Balance::Other {
+        currency: "USD".parse().unwrap(),
+        issuer: issuer.into(),
+        value: bigdecimal::BigDecimal::from_str("0.25").unwrap(),
+    };
+    let bitstamp = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+    let tx_json = PaymentBuilder::new(&account, &destination, usd(bitstamp))
+        .flags(PaymentFlags::PARTIAL_PAYMENT)
+        .build()
+        .unwrap();
+    assert_eq!(
+        tx_json,
+        json!({
+            "TransactionType": "Payment",
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Destination": "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "value": "0.25"
+            },
+            "DestinationTag": 1,
+            "Flags": 131072
+        })
+    );
+
+    assert!(PaymentBuilder::new(&account, &destination, usd(""))
+        .build()
+        .is_err());
+    assert!(PaymentBuilder::new(&account, &destination, usd(bitstamp))
+        .destination_tag(2)
+        .build()
+        .is_err());
+}
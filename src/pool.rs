@@ -0,0 +1,103 @@
+use failure::{err_msg, Error};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Builds a reqwest client, with the given timeout or reqwest's own default.
+pub(crate) type Connect = dyn Fn(Option<Duration>) -> Result<reqwest::Client, String> + Send + Sync;
+
+struct Clients {
+    by_timeout: HashMap<Option<Duration>, reqwest::Client>,
+    used: Instant,
+}
+
+/**
+ * The reqwest clients all calls share, built once so tcp and tls connections stay open
+ * between calls. A reqwest 0.9 client has one timeout for all its requests, so there is
+ * a client per timeout in use. reqwest 0.9 has no idle timeout for its pool either, so
+ * once no call went out for `idle_timeout` the clients are replaced, which closes every
+ * connection they kept.
+ */
+pub(crate) struct Pool {
+    clients: Mutex<Clients>,
+    idle_timeout: Option<Duration>,
+    connect: Box<Connect>,
+}
+
+impl Pool {
+    pub(crate) fn new(
+        connect: Box<Connect>,
+        idle_timeout: Option<Duration>,
+    ) -> Result<Pool, String> {
+        let mut by_timeout = HashMap::new();
+        by_timeout.insert(None, connect(None)?);
+        Ok(Pool {
+            clients: Mutex::new(Clients {
+                by_timeout,
+                used: Instant::now(),
+            }),
+            idle_timeout,
+            connect,
+        })
+    }
+
+    pub(crate) fn client(&self, timeout: Option<Duration>) -> Result<reqwest::Client, Error> {
+        let mut clients = self.clients.lock().unwrap();
+        if let Some(idle_timeout) = self.idle_timeout {
+            if clients.used.elapsed() >= idle_timeout {
+                clients.by_timeout.clear();
+            }
+        }
+        clients.used = Instant::now();
+        if let Some(client) = clients.by_timeout.get(&timeout) {
+            return Ok(client.clone());
+        }
+        let client = (self.connect)(timeout).map_err(err_msg)?;
+        clients.by_timeout.insert(timeout, client.clone());
+        Ok(client)
+    }
+
+    /// Marks the end of a call, its connection is idle from here on.
+    pub(crate) fn release(&self) {
+        self.clients.lock().unwrap().used = Instant::now();
+    }
+}
+
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A pool counting how many clients it built.
+#[cfg(test)]
+fn counting_pool(idle_timeout: Option<Duration>) -> (Pool, std::sync::Arc<AtomicUsize>) {
+    let built = std::sync::Arc::new(AtomicUsize::new(0));
+    let counter = built.clone();
+    let connect = move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok::<_, String>(reqwest::Client::new())
+    };
+    (Pool::new(Box::new(connect), idle_timeout).unwrap(), built)
+}
+
+#[test]
+fn pool_idle_timeout_test() {
+    let (pool, built) = counting_pool(Some(Duration::from_millis(50)));
+    pool.client(None).unwrap();
+    pool.release();
+    pool.client(None).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    std::thread::sleep(Duration::from_millis(100));
+    pool.client(None).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn pool_client_per_timeout_test() {
+    let (pool, built) = counting_pool(None);
+    let short = Some(Duration::from_millis(100));
+    pool.client(short).unwrap();
+    pool.client(short).unwrap();
+    pool.client(None).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 2);
+    pool.client(Some(Duration::from_secs(5))).unwrap();
+    assert_eq!(built.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,43 @@
+use crate::{Account, AccountInfoParams, ValidatedLedgerInfo, XRPClient, XrpError};
+use bigdecimal::BigDecimal;
+use failure::{format_err, Error};
+
+impl ValidatedLedgerInfo {
+    /**
+     * The XRP an account with `owner_count` objects has to hold, `base + inc * owner_count`. [1]
+     * 1: https://xrpl.org/reserves.html
+     */
+    pub fn account_reserve(&self, owner_count: u64) -> BigDecimal {
+        let owners = BigDecimal::from(owner_count);
+        self.reserve_base_xrp.clone() + self.reserve_inc_xrp.clone() * owners
+    }
+}
+
+impl XRPClient {
+    /// `account_reserve` with the reserves of the latest validated ledger.
+    /// An `Err` while the server has no validated ledger.
+    pub fn account_reserve(&self, owner_count: u64) -> Result<BigDecimal, Error> {
+        let server_info = self.server_info()?;
+        server_info
+            .info
+            .validated_ledger
+            .map(|ledger| ledger.account_reserve(owner_count))
+            .ok_or_else(|| format_err!("The server has no validated ledger"))
+    }
+
+    /**
+     * Whether `account` is in the latest validated ledger. Accounts only get there once
+     * sent at least the base reserve, so `false` means never funded or deleted; errors
+     * other than `actNotFound` are passed on.
+     */
+    pub fn account_exists(&self, account: &Account) -> Result<bool, Error> {
+        let params = AccountInfoParams::builder(account).build();
+        match self.account_info(params) {
+            Ok(_) => Ok(true),
+            Err(error) => match error.downcast_ref::<XrpError>() {
+                Some(xrp_error) if xrp_error.code() == "actNotFound" => Ok(false),
+                _ => Err(error),
+            },
+        }
+    }
+}
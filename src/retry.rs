@@ -0,0 +1,227 @@
+use crate::XrpError;
+use failure::Error;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Read only methods that are safe to send again. Anything else, `submit` above all, never is.
+pub const IDEMPOTENT_METHODS: &[&str] = &[
+    "account_channels",
+    "account_currencies",
+    "account_info",
+    "account_lines",
+    "account_nfts",
+    "account_objects",
+    "account_offers",
+    "account_tx",
+    "amm_info",
+    "book_offers",
+    "channel_verify",
+    "deposit_authorized",
+    "feature",
+    "fee",
+    "gateway_balances",
+    "get_aggregate_price",
+    "ledger",
+    "ledger_closed",
+    "ledger_current",
+    "ledger_data",
+    "ledger_entry",
+    "manifest",
+    "nft_buy_offers",
+    "nft_sell_offers",
+    "noripple_check",
+    "ping",
+    "random",
+    "ripple_path_find",
+    "server_info",
+    "server_state",
+    "transaction_entry",
+    "tx",
+    "tx_history",
+];
+
+/**
+ * Error codes rippled uses when it is overloaded or not synced yet. [1]
+ * 1: https://xrpl.org/error-formatting.html#universal-errors
+ */
+pub(crate) const TRANSIENT_ERRORS: &[&str] = &["noNetwork", "tooBusy", "slowDown"];
+
+/// rippled busy or out of sync, or an HTTP 503 from it or a proxy in front of it.
+pub fn is_transient(error: &Error) -> bool {
+    if let Some(xrp_error) = error.downcast_ref::<XrpError>() {
+        return TRANSIENT_ERRORS.contains(&xrp_error.code());
+    }
+    if let Some(http_error) = error.downcast_ref::<reqwest::Error>() {
+        return http_error.status() == Some(reqwest::StatusCode::SERVICE_UNAVAILABLE);
+    }
+    false
+}
+
+type ShouldRetry = dyn Fn(&Error, u32) -> bool + Send + Sync;
+
+/**
+ * Re-sends idempotent calls that failed for a transient reason, waiting
+ * `base_delay * 2^attempt` (capped at `max_delay`, half of it jittered) in between.
+ */
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    should_retry: Arc<ShouldRetry>,
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            base_delay,
+            max_delay: Duration::from_secs(30),
+            should_retry: Arc::new(|error, _| is_transient(error)),
+        }
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Replaces `is_transient`, `attempt` starts at 1 for the first failure.
+    pub fn should_retry<F>(mut self, should_retry: F) -> Self
+    where
+        F: Fn(&Error, u32) -> bool + Send + Sync + 'static,
+    {
+        self.should_retry = Arc::new(should_retry);
+        self
+    }
+
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .checked_mul(1 << attempt.min(31))
+            .unwrap_or(self.max_delay);
+        let capped = std::cmp::min(exponential, self.max_delay);
+        let half = capped / 2;
+        let jitter = rand::thread_rng().gen_range(0, half.as_micros() as u64 + 1);
+        half + Duration::from_micros(jitter)
+    }
+
+    /// How long to wait before sending `method` again after its `attempt`th failure, if at all.
+    pub(crate) fn retry_after(
+        &self,
+        method: &str,
+        error: &Error,
+        attempt: u32,
+    ) -> Option<Duration> {
+        let retry = IDEMPOTENT_METHODS.contains(&method)
+            && attempt <= self.max_retries
+            && (self.should_retry)(error, attempt);
+        if retry {
+            Some(self.backoff(attempt - 1))
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Any other call wrapped as `call`, `method` being the rpc it sends. Calls of a client
+     * built with `XRPClientBuilder::retry` are already retried and need no wrapping.
+     */
+    pub fn call<T, F>(&self, method: &str, mut call: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Result<T, Error>,
+    {
+        let mut attempt = 0;
+        loop {
+            let error = match call() {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+            attempt += 1;
+            match self.retry_after(method, &error, attempt) {
+                Some(delay) => std::thread::sleep(delay),
+                None => return Err(error),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn busy() -> Error {
+    XrpError {
+        error: "tooBusy".into(),
+        error_code: Some(9),
+        error_message: Some("The server is too busy to help you now.".into()),
+        request: None,
+        status: Some("error".into()),
+    }
+    .into()
+}
+
+#[test]
+fn retry_then_succeed_test() {
+    let policy = RetryPolicy::new(3, Duration::from_millis(1));
+    let mut attempts = 0;
+    let result = policy.call("account_info", || {
+        attempts += 1;
+        if attempts <= 2 {
+            Err(busy())
+        } else {
+            Ok(7)
+        }
+    });
+    assert_eq!(result.unwrap(), 7);
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn retry_never_submit_test() {
+    let policy = RetryPolicy::new(3, Duration::from_millis(1));
+    let mut attempts = 0;
+    let result = policy.call("submit", || {
+        attempts += 1;
+        Err::<u32, _>(busy())
+    });
+    assert!(result.is_err());
+    assert_eq!(attempts, 1);
+}
+
+#[test]
+fn retry_custom_classifier_test() {
+    let policy =
+        RetryPolicy::new(5, Duration::from_millis(1)).should_retry(|_, attempt| attempt < 2);
+    let mut attempts = 0;
+    let result = policy.call("ledger", || {
+        attempts += 1;
+        Err::<u32, _>(busy())
+    });
+    assert_eq!(
+        result
+            .unwrap_err()
+            .downcast_ref::<XrpError>()
+            .map(XrpError::code),
+        Some("tooBusy")
+    );
+    assert_eq!(attempts, 2);
+}
+
+#[test]
+fn idempotent_methods_test() {
+    let mut sorted = IDEMPOTENT_METHODS.to_vec();
+    sorted.sort_unstable();
+    assert_eq!(sorted, IDEMPOTENT_METHODS);
+    for method in &["submit", "submit_multisigned", "sign", "sign_for", "channel_authorize"] {
+        assert!(!IDEMPOTENT_METHODS.contains(method), "{} must not be retried", method);
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/**
+ * A token bucket holding up to `burst` tokens, refilled at `requests_per_sec`, plus a
+ * cap on the calls in flight. A 0 for the rate or the cap turns that part off.
+ */
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    requests_per_sec: f64,
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+    max_in_flight: usize,
+    in_flight: Mutex<usize>,
+    released: Condvar,
+}
+
+/// Holds one of the `max_in_flight` slots until dropped.
+pub(crate) struct Permit<'a> {
+    throttle: &'a Throttle,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.throttle.max_in_flight > 0 {
+            *self.throttle.in_flight.lock().unwrap() -= 1;
+            self.throttle.released.notify_one();
+        }
+    }
+}
+
+impl Throttle {
+    pub(crate) fn new(max_in_flight: usize, requests_per_sec: f64, burst: usize) -> Throttle {
+        let capacity = burst.max(1) as f64;
+        Throttle {
+            requests_per_sec,
+            capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled: Instant::now(),
+            }),
+            max_in_flight,
+            in_flight: Mutex::new(0),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Waits for a slot and then for `tokens` tokens, a batch takes one per call in it.
+    pub(crate) fn acquire(&self, tokens: usize) -> Permit<'_> {
+        if self.max_in_flight > 0 {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            while *in_flight >= self.max_in_flight {
+                in_flight = self.released.wait(in_flight).unwrap();
+            }
+            *in_flight += 1;
+        }
+        let permit = Permit { throttle: self };
+        for _ in 0..tokens {
+            thread::sleep(self.reserve());
+        }
+        permit
+    }
+
+    /**
+     * Takes a token ahead of time, to be used only once the returned wait is over. The
+     * bucket goes below zero meanwhile, so later callers wait behind this one.
+     */
+    pub(crate) fn reserve(&self) -> Duration {
+        if self.requests_per_sec <= 0.0 {
+            return Duration::from_secs(0);
+        }
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_sec).min(self.capacity);
+        bucket.refilled = now;
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.requests_per_sec)
+        }
+    }
+}
+
+#[test]
+fn throttle_spacing_test() {
+    let throttle = Throttle::new(0, 20.0, 1);
+    let start = Instant::now();
+    for _ in 0..5 {
+        throttle.acquire(1);
+    }
+    // the first token is there from the start, the other 4 take 50ms each
+    assert!(start.elapsed() >= Duration::from_millis(195));
+}
+
+#[test]
+fn throttle_reserve_test() {
+    let throttle = Throttle::new(0, 10.0, 2);
+    assert_eq!(throttle.reserve(), Duration::from_secs(0));
+    assert_eq!(throttle.reserve(), Duration::from_secs(0));
+    let third = throttle.reserve();
+    let fourth = throttle.reserve();
+    assert!(third > Duration::from_millis(90) && third <= Duration::from_millis(100));
+    assert!(fourth > Duration::from_millis(190) && fourth <= Duration::from_millis(200));
+}
+
+#[test]
+fn throttle_in_flight_test() {
+    let throttle = Throttle::new(2, 0.0, 1);
+    let first = throttle.acquire(1);
+    let _second = throttle.acquire(1);
+    assert_eq!(*throttle.in_flight.lock().unwrap(), 2);
+    drop(first);
+    assert_eq!(*throttle.in_flight.lock().unwrap(), 1);
+    let _third = throttle.acquire(1);
+    assert_eq!(*throttle.in_flight.lock().unwrap(), 2);
+}
+
+#[test]
+fn throttle_burst_test() {
+    let throttle = Throttle::new(0, 10.0, 5);
+    let start = Instant::now();
+    for _ in 0..5 {
+        throttle.acquire(1);
+    }
+    assert!(start.elapsed() < Duration::from_millis(90));
+    throttle.acquire(1);
+    assert!(start.elapsed() >= Duration::from_millis(95));
+}
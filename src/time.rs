@@ -0,0 +1,75 @@
+use bigdecimal::BigDecimal;
+use num_traits::ToPrimitive;
+
+/**
+ * Ledger times count seconds since 2000-01-01T00:00:00Z, not since the Unix epoch. [1]
+ * 1: https://xrpl.org/basic-data-types.html#specifying-time
+ */
+pub const RIPPLE_EPOCH_OFFSET: i64 = 946_684_800;
+
+/// Whole Unix seconds, fractions are dropped and out of range values saturate.
+pub fn ripple_time_to_unix(ripple_time: &BigDecimal) -> i64 {
+    let seconds = ripple_time.with_scale(0).to_i64().unwrap_or_else(|| {
+        if ripple_time < &BigDecimal::from(0) {
+            i64::MIN
+        } else {
+            i64::MAX
+        }
+    });
+    seconds.saturating_add(RIPPLE_EPOCH_OFFSET)
+}
+
+#[cfg(feature = "chrono")]
+fn to_datetime(ripple_time: &BigDecimal) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+    chrono::Utc
+        .timestamp_opt(ripple_time_to_unix(ripple_time), 0)
+        .single()
+}
+
+#[cfg(feature = "chrono")]
+impl crate::NestedLedgerInfo {
+    /// `close_time` as a date, `None` for ledgers that have not closed yet.
+    pub fn close_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.close_time.as_ref().and_then(to_datetime)
+    }
+
+    pub fn parent_close_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.parent_close_time.as_ref().and_then(to_datetime)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl crate::TransactionInfo {
+    /// The close time of the ledger the transaction is in, `None` while it is pending.
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.date.as_ref().and_then(to_datetime)
+    }
+}
+
+#[test]
+fn ripple_time_to_unix_test() {
+    assert_eq!(ripple_time_to_unix(&BigDecimal::from(0)), 946_684_800);
+    // 2020-07-16T20:53:40Z
+    assert_eq!(ripple_time_to_unix(&BigDecimal::from(648_248_020)), 1_594_932_820);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn close_datetime_test() {
+    let ledger: crate::NestedLedgerInfo = serde_json::from_str(
+        r#"{
+  "closed": true,
+  "close_time": 648248020,
+  "ledger_index": "56865245",
+  "parent_hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+  "seqNum": "56865245"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ledger.close_datetime().unwrap().to_rfc3339(),
+        "2020-07-16T20:53:40+00:00"
+    );
+    assert!(ledger.parent_close_datetime().is_none());
+}
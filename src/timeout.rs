@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+/// The error a call gives when it takes longer than its timeout, downcast to tell it apart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedOut {
+    pub method: String,
+    pub timeout: Duration,
+}
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} did not answer within {:?}", self.method, self.timeout)
+    }
+}
+
+impl failure::Fail for TimedOut {}
+
+/**
+ * A deadline for each call, `default` unless the method has its own, handed to
+ * `XRPClientBuilder::timeout`.
+ */
+#[derive(Debug, Clone)]
+pub struct TimeoutPolicy {
+    default: Duration,
+    per_method: HashMap<String, Duration>,
+}
+
+impl TimeoutPolicy {
+    pub fn new(default: Duration) -> Self {
+        TimeoutPolicy {
+            default,
+            per_method: HashMap::new(),
+        }
+    }
+
+    /// e.g. a longer deadline for `ledger` with `full: true` than for `fee`.
+    pub fn with_timeout(mut self, method: &str, timeout: Duration) -> Self {
+        self.per_method.insert(method.into(), timeout);
+        self
+    }
+
+    pub fn timeout(&self, method: &str) -> Duration {
+        self.per_method.get(method).cloned().unwrap_or(self.default)
+    }
+}
+
+#[test]
+fn timeout_per_method_test() {
+    let policy = TimeoutPolicy::new(Duration::from_millis(20))
+        .with_timeout("ledger", Duration::from_millis(500));
+    assert_eq!(policy.timeout("ledger"), Duration::from_millis(500));
+    assert_eq!(policy.timeout("fee"), Duration::from_millis(20));
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::time::Duration;
+use tracing::Span;
+
+/**
+ * The `xrp_rpc` span every `XRPClient` call runs in, with the `method` and `url` fields.
+ * Params are never recorded, so secrets given to `sign` or `channel_authorize` stay out
+ * of the logs.
+ */
+pub(crate) fn span(method: &str, url: &str) -> Span {
+    tracing::info_span!(
+        "xrp_rpc",
+        method = method,
+        url = url,
+        latency_ms = tracing::field::Empty,
+        error_code = tracing::field::Empty
+    )
+}
+
+/// `latency_ms` once the call returned, and `error_code` and a warning if it failed.
+pub(crate) fn record(span: &Span, latency: Duration, failure: Option<(&str, &dyn fmt::Display)>) {
+    span.record("latency_ms", latency.as_millis() as u64);
+    if let Some((code, error)) = failure {
+        span.record("error_code", code);
+        tracing::warn!(error = %error, "rpc call failed");
+    }
+}
+
+#[cfg(test)]
+mod recorder {
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata};
+
+    /// Keeps every span field as `(name, value)` in `fields`.
+    pub struct Recorder {
+        pub fields: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl Visit for &Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.fields
+                .lock()
+                .unwrap()
+                .push((field.name().into(), format!("{:?}", value)));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields
+                .lock()
+                .unwrap()
+                .push((field.name().into(), value.into()));
+        }
+    }
+
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            span.record(&mut &*self);
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record) {
+            values.record(&mut &*self);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn traced_call_test() {
+    use crate::{MockTransport, XRPClient, XrpError};
+
+    let transport = MockTransport::new();
+    transport.respond(
+        "fee",
+        serde_json::json!({
+            "error": "tooBusy",
+            "error_code": 9,
+            "error_message": "The server is too busy to help you now.",
+            "status": "error"
+        }),
+    );
+    let client = XRPClient::with_transport(&transport);
+    let fields = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let subscriber = recorder::Recorder {
+        fields: fields.clone(),
+    };
+    let error = tracing::subscriber::with_default(subscriber, || client.fee()).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<XrpError>().map(XrpError::code),
+        Some("tooBusy")
+    );
+    let fields = fields.lock().unwrap();
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.clone())
+    };
+    assert_eq!(field("method"), Some("fee".into()));
+    assert_eq!(field("url"), Some(transport.url().into()));
+    assert_eq!(field("error_code"), Some("tooBusy".into()));
+    assert!(field("latency_ms").is_some());
+}
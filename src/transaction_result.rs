@@ -0,0 +1,103 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/**
+ * Result codes are grouped by their three letter prefix. [1]
+ * 1: https://xrpl.org/transaction-results.html
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TransactionResult {
+    /// Success
+    Tes(String),
+    /// Failed, but the fee was claimed
+    Tec(String),
+    /// Failed locally, not forwarded
+    Tel(String),
+    /// Malformed transaction
+    Tem(String),
+    /// Failed, not applied, could never succeed in this ledger
+    Tef(String),
+    /// Retry, could succeed in a later ledger
+    Ter(String),
+    Other(String),
+}
+
+impl TransactionResult {
+    pub fn code(&self) -> &str {
+        match self {
+            TransactionResult::Tes(code)
+            | TransactionResult::Tec(code)
+            | TransactionResult::Tel(code)
+            | TransactionResult::Tem(code)
+            | TransactionResult::Tef(code)
+            | TransactionResult::Ter(code)
+            | TransactionResult::Other(code) => code,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, TransactionResult::Tes(_))
+    }
+
+    /// Only `tec` codes are included in a ledger and destroy the fee without succeeding.
+    pub fn is_claimed_fee(&self) -> bool {
+        matches!(self, TransactionResult::Tec(_))
+    }
+}
+
+impl From<String> for TransactionResult {
+    fn from(code: String) -> Self {
+        let prefix: String = code.chars().take(3).collect();
+        match prefix.as_str() {
+            "tes" => TransactionResult::Tes(code),
+            "tec" => TransactionResult::Tec(code),
+            "tel" => TransactionResult::Tel(code),
+            "tem" => TransactionResult::Tem(code),
+            "tef" => TransactionResult::Tef(code),
+            "ter" => TransactionResult::Ter(code),
+            _ => TransactionResult::Other(code),
+        }
+    }
+}
+
+impl std::fmt::Display for TransactionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for TransactionResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for TransactionResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(TransactionResult::from)
+    }
+}
+
+#[test]
+fn transaction_result_test() {
+    let success: TransactionResult = serde_json::from_str(r#""tesSUCCESS""#).unwrap();
+    assert!(success.is_success());
+    assert!(!success.is_claimed_fee());
+    assert_eq!(success.code(), "tesSUCCESS");
+
+    let path_dry: TransactionResult = serde_json::from_str(r#""tecPATH_DRY""#).unwrap();
+    assert!(path_dry.is_claimed_fee());
+    assert!(!path_dry.is_success());
+
+    let unknown: TransactionResult = serde_json::from_str(r#""xyzNEW_CODE""#).unwrap();
+    assert_eq!(unknown, TransactionResult::Other("xyzNEW_CODE".into()));
+    assert_eq!(
+        serde_json::to_string(&unknown).unwrap(),
+        r#""xyzNEW_CODE""#
+    );
+}
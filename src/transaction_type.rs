@@ -0,0 +1,133 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! transaction_types {
+    ($($variant:ident => $token:literal = $code:literal,)*) => {
+        /**
+         * The `TransactionType` field, types newer than this crate end up in `Other`. [1]
+         * 1: https://xrpl.org/transaction-types.html
+         */
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum TransactionType {
+            $($variant,)*
+            Other(String),
+        }
+
+        impl TransactionType {
+            /// The exact XRPL token, e.g. `NFTokenMint`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(TransactionType::$variant => $token,)*
+                    TransactionType::Other(token) => token,
+                }
+            }
+
+            /// The type for a binary format code, `None` for codes this crate does not know.
+            pub fn from_code(code: u16) -> Option<TransactionType> {
+                match code {
+                    $($code => Some(TransactionType::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<String> for TransactionType {
+            fn from(token: String) -> Self {
+                match token.as_str() {
+                    $($token => TransactionType::$variant,)*
+                    _ => TransactionType::Other(token),
+                }
+            }
+        }
+    };
+}
+
+transaction_types! {
+    AccountDelete => "AccountDelete" = 21,
+    AccountSet => "AccountSet" = 3,
+    AmmBid => "AMMBid" = 39,
+    AmmCreate => "AMMCreate" = 35,
+    AmmDelete => "AMMDelete" = 40,
+    AmmDeposit => "AMMDeposit" = 36,
+    AmmVote => "AMMVote" = 38,
+    AmmWithdraw => "AMMWithdraw" = 37,
+    CheckCancel => "CheckCancel" = 18,
+    CheckCash => "CheckCash" = 17,
+    CheckCreate => "CheckCreate" = 16,
+    Clawback => "Clawback" = 30,
+    DepositPreauth => "DepositPreauth" = 19,
+    EscrowCancel => "EscrowCancel" = 4,
+    EscrowCreate => "EscrowCreate" = 1,
+    EscrowFinish => "EscrowFinish" = 2,
+    NfTokenAcceptOffer => "NFTokenAcceptOffer" = 29,
+    NfTokenBurn => "NFTokenBurn" = 26,
+    NfTokenCancelOffer => "NFTokenCancelOffer" = 28,
+    NfTokenCreateOffer => "NFTokenCreateOffer" = 27,
+    NfTokenMint => "NFTokenMint" = 25,
+    OfferCancel => "OfferCancel" = 8,
+    OfferCreate => "OfferCreate" = 7,
+    Payment => "Payment" = 0,
+    PaymentChannelClaim => "PaymentChannelClaim" = 15,
+    PaymentChannelCreate => "PaymentChannelCreate" = 13,
+    PaymentChannelFund => "PaymentChannelFund" = 14,
+    SetRegularKey => "SetRegularKey" = 5,
+    SignerListSet => "SignerListSet" = 12,
+    TicketCreate => "TicketCreate" = 10,
+    TrustSet => "TrustSet" = 20,
+    EnableAmendment => "EnableAmendment" = 100,
+    SetFee => "SetFee" = 101,
+    UnlModify => "UNLModify" = 102,
+}
+
+impl TransactionType {
+    /// Made by the network in flag ledgers, not signed or paid for by any account.
+    pub fn is_pseudo(&self) -> bool {
+        matches!(
+            self,
+            TransactionType::EnableAmendment | TransactionType::SetFee | TransactionType::UnlModify
+        )
+    }
+}
+
+impl std::fmt::Display for TransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for TransactionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TransactionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(TransactionType::from)
+    }
+}
+
+#[test]
+fn transaction_type_test() {
+    let payment: TransactionType = serde_json::from_str(r#""Payment""#).unwrap();
+    assert_eq!(payment, TransactionType::Payment);
+    assert_eq!(serde_json::to_string(&payment).unwrap(), r#""Payment""#);
+
+    let mint: TransactionType = serde_json::from_str(r#""NFTokenMint""#).unwrap();
+    assert_eq!(mint, TransactionType::NfTokenMint);
+    assert_eq!(mint.to_string(), "NFTokenMint");
+
+    let unknown: TransactionType = serde_json::from_str(r#""SomeFutureTx""#).unwrap();
+    assert_eq!(unknown, TransactionType::Other("SomeFutureTx".into()));
+    assert_eq!(
+        serde_json::to_string(&unknown).unwrap(),
+        r#""SomeFutureTx""#
+    );
+    assert!(TransactionType::UnlModify.is_pseudo());
+    assert!(!TransactionType::Payment.is_pseudo());
+}
@@ -0,0 +1,327 @@
+use crate::{Account, Balance, PathAlternative, XrpError};
+use bigdecimal::BigDecimal;
+use failure::{format_err, Error};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_json::value::Value;
+use std::time::Duration;
+use tungstenite::client::AutoStream;
+use tungstenite::{Message, WebSocket};
+
+/**
+ * Sent on the `ledger` stream whenever the consensus process declares a new validated ledger. [1]
+ * 1: https://xrpl.org/subscribe.html#ledger-stream
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct LedgerClosedEvent {
+    pub fee_base: BigDecimal,
+    pub fee_ref: Option<BigDecimal>,
+    pub ledger_hash: String,
+    pub ledger_index: BigDecimal,
+    pub ledger_time: Option<BigDecimal>,
+    pub reserve_base: BigDecimal,
+    pub reserve_inc: Option<BigDecimal>,
+    pub txn_count: BigDecimal,
+    pub validated_ledgers: Option<String>,
+}
+
+/**
+ * Every `ledgerClosed` event of a rippled WebSocket endpoint, blocking until the next one.
+ * A dropped connection is reopened and subscribed again after `reconnect_delay`,
+ * a failed reconnect is yielded as an `Err` and retried on the following `next`.
+ */
+pub struct LedgerSubscription {
+    url: url::Url,
+    socket: Option<WebSocket<AutoStream>>,
+    reconnect_delay: Duration,
+}
+
+impl std::fmt::Debug for LedgerSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("LedgerSubscription")
+            .field("url", &self.url.as_str())
+            .field("connected", &self.socket.is_some())
+            .field("reconnect_delay", &self.reconnect_delay)
+            .finish()
+    }
+}
+
+/// `url` is the WebSocket endpoint, e.g. `wss://s1.ripple.com/`.
+pub fn subscribe_ledgers(url: &str) -> Result<LedgerSubscription, Error> {
+    let mut subscription = LedgerSubscription {
+        url: url::Url::parse(url)?,
+        socket: None,
+        reconnect_delay: Duration::from_secs(1),
+    };
+    subscription.connect()?;
+    Ok(subscription)
+}
+
+impl LedgerSubscription {
+    pub fn reconnect_delay(mut self, reconnect_delay: Duration) -> Self {
+        self.reconnect_delay = reconnect_delay;
+        self
+    }
+
+    fn connect(&mut self) -> Result<(), Error> {
+        let (mut socket, _) = tungstenite::connect(self.url.clone())?;
+        socket.write_message(Message::Text(
+            json!({
+                "id": "ledger",
+                "command": "subscribe",
+                "streams": ["ledger"],
+            })
+            .to_string(),
+        ))?;
+        self.socket = Some(socket);
+        Ok(())
+    }
+}
+
+impl Iterator for LedgerSubscription {
+    type Item = Result<LedgerClosedEvent, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.socket.is_none() {
+                std::thread::sleep(self.reconnect_delay);
+                if let Err(error) = self.connect() {
+                    return Some(Err(error));
+                }
+            }
+            let text = match self.socket.as_mut().unwrap().read_message() {
+                Ok(Message::Text(text)) => text,
+                Ok(_) => continue,
+                Err(_) => {
+                    self.socket = None;
+                    continue;
+                }
+            };
+            match parse_message(&text) {
+                Ok(Some(event)) => return Some(Ok(event)),
+                Ok(None) => continue,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+/// The reply to `subscribe` and other stream types are skipped, error replies are not.
+fn parse_message(text: &str) -> Result<Option<LedgerClosedEvent>, Error> {
+    let message: Value = serde_json::from_str(text)?;
+    if let Some(error) = XrpError::from_response(&message) {
+        return Err(error.into());
+    }
+    if message["type"] == "ledgerClosed" {
+        Ok(Some(serde_json::from_value(message)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/**
+ * The `create` subcommand of `path_find`, rippled keeps the paths up to date until it is closed. [1]
+ * 1: https://xrpl.org/path_find.html#path_find-create
+ */
+#[derive(Serialize, Debug, Clone)]
+pub struct PathFindParams<'a> {
+    pub source_account: &'a Account,
+    pub destination_account: &'a Account,
+    pub destination_amount: Balance,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_max: Option<Balance>,
+}
+
+/// The reply to `create` and every `path_find` message after it, `full_reply` once the search is done.
+#[derive(Deserialize, Debug)]
+pub struct PathFindUpdate {
+    pub alternatives: Vec<PathAlternative>,
+    pub destination_account: Option<String>,
+    pub full_reply: bool,
+    pub id: Value,
+    pub source_account: Option<String>,
+}
+
+/**
+ * A WebSocket connection for `path_find`. rippled keeps one path-finding request per
+ * connection and silently replaces it on a second `create`, so `path_find_create`
+ * refuses to start another until `path_find_close`.
+ */
+pub struct PathFindConnection {
+    socket: WebSocket<AutoStream>,
+    next_id: u64,
+    active: Option<u64>,
+}
+
+impl std::fmt::Debug for PathFindConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PathFindConnection")
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+/// `url` is the WebSocket endpoint, e.g. `wss://s1.ripple.com/`.
+pub fn connect_path_find(url: &str) -> Result<PathFindConnection, Error> {
+    let (socket, _) = tungstenite::connect(url::Url::parse(url)?)?;
+    Ok(PathFindConnection {
+        socket,
+        next_id: 1,
+        active: None,
+    })
+}
+
+impl PathFindConnection {
+    pub fn path_find_create(&mut self, params: &PathFindParams) -> Result<PathFindUpdates<'_>, Error> {
+        if self.active.is_some() {
+            return Err(format_err!(
+                "A path_find request is already open on this connection, close it first"
+            ));
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut command = serde_json::to_value(params)?;
+        command["id"] = json!(id);
+        command["command"] = json!("path_find");
+        command["subcommand"] = json!("create");
+        self.socket.write_message(Message::Text(command.to_string()))?;
+        self.active = Some(id);
+        Ok(PathFindUpdates { connection: self })
+    }
+
+    /// Updates still on their way for the closed request are dropped.
+    pub fn path_find_close(&mut self) -> Result<(), Error> {
+        if self.active.take().is_none() {
+            return Ok(());
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.socket.write_message(Message::Text(
+            json!({
+                "id": id,
+                "command": "path_find",
+                "subcommand": "close",
+            })
+            .to_string(),
+        ))?;
+        Ok(())
+    }
+}
+
+/// The updates of the open request, blocking until the next one. Ends once the request is closed.
+#[derive(Debug)]
+pub struct PathFindUpdates<'a> {
+    connection: &'a mut PathFindConnection,
+}
+
+impl<'a> PathFindUpdates<'a> {
+    pub fn close(self) -> Result<(), Error> {
+        self.connection.path_find_close()
+    }
+}
+
+impl<'a> Iterator for PathFindUpdates<'a> {
+    type Item = Result<PathFindUpdate, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let id = self.connection.active?;
+            let text = match self.connection.socket.read_message() {
+                Ok(Message::Text(text)) => text,
+                Ok(_) => continue,
+                Err(error) => {
+                    self.connection.active = None;
+                    return Some(Err(error.into()));
+                }
+            };
+            match parse_path_find(&text) {
+                Ok(Some(ref update)) if update.id != json!(id) => continue,
+                Ok(Some(update)) => return Some(Ok(update)),
+                Ok(None) => continue,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+/// The `create` reply carries the first update in `result`, later ones come as `path_find` messages.
+fn parse_path_find(text: &str) -> Result<Option<PathFindUpdate>, Error> {
+    let message: Value = serde_json::from_str(text)?;
+    if let Some(error) = XrpError::from_response(&message) {
+        return Err(error.into());
+    }
+    if message["type"] == "path_find" {
+        return Ok(Some(serde_json::from_value(message)?));
+    }
+    match message.get("result") {
+        Some(result) if result.get("alternatives").is_some() => {
+            let mut result = result.clone();
+            result["id"] = message["id"].clone();
+            Ok(Some(serde_json::from_value(result)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[test]
+fn ws_parse_message_test() {
+    let event = parse_message(
+        r#"{
+  "type": "ledgerClosed",
+  "fee_base": 10,
+  "fee_ref": 10,
+  "ledger_hash": "687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464",
+  "ledger_index": 7125358,
+  "ledger_time": 455751310,
+  "reserve_base": 20000000,
+  "reserve_inc": 5000000,
+  "txn_count": 7,
+  "validated_ledgers": "32570-7125358"
+}"#,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(event.ledger_index, BigDecimal::from(7_125_358));
+    assert_eq!(event.txn_count, BigDecimal::from(7));
+
+    assert!(parse_message(
+        r#"{"id": "ledger", "result": {"ledger_index": 7125358}, "status": "success", "type": "response"}"#
+    )
+    .unwrap()
+    .is_none());
+    assert!(parse_message(
+        r#"{"error": "noPermission", "id": "ledger", "status": "error", "type": "response"}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn ws_parse_path_find_test() {
+    let update = parse_path_find(
+        r#"{
+  "id": 1,
+  "result": {
+    "alternatives": [],
+    "destination_account": "rKT4JX4cCof6LcDYRz8o3rGRu7qxzZ2Zwj",
+    "full_reply": false,
+    "source_account": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59"
+  },
+  "status": "success",
+  "type": "response"
+}"#,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(update.id, json!(1));
+    assert!(!update.full_reply);
+
+    assert!(parse_path_find(
+        r#"{"id": 2, "result": {"closed": true}, "status": "success", "type": "response"}"#
+    )
+    .unwrap()
+    .is_none());
+    assert!(parse_path_find(
+        r#"{"error": "noEvents", "id": 1, "status": "error", "type": "response"}"#
+    )
+    .is_err());
+}
@@ -0,0 +1,62 @@
+#![cfg(all(feature = "cache", feature = "mock"))]
+
+use serde_json::json;
+use throttled_xrp_rpc::{LedgerInfoParams, MockTransport, RequestLedgerIndex, XRPClient};
+
+fn ledger_params(ledger_index: RequestLedgerIndex) -> LedgerInfoParams {
+    LedgerInfoParams {
+        ledger_hash: None,
+        ledger_index: Some(ledger_index),
+        full: None,
+        accounts: None,
+        transactions: None,
+        expand: None,
+        owner_funds: None,
+        binary: None,
+        queue: None,
+    }
+}
+
+#[test]
+fn cache_validated_ledger_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "ledger",
+        json!({
+            "ledger": {
+                "closed": true,
+                "ledger_index": "56865245",
+                "parent_hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+                "seqNum": "56865245"
+            },
+            "ledger_hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+            "ledger_index": 56865245,
+            "status": "success",
+            "validated": true
+        }),
+    );
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .cache(16)
+        .build_cached()
+        .unwrap();
+    let by_index = || {
+        ledger_params(RequestLedgerIndex::Number {
+            ledger_index: 56_865_245.into(),
+        })
+    };
+
+    let first = client.ledger(by_index()).unwrap();
+    let second = client.ledger(by_index()).unwrap();
+    assert_eq!(first.ledger_hash, second.ledger_hash);
+    assert_eq!(transport.requests().len(), 1);
+
+    let validated = || {
+        ledger_params(RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        })
+    };
+    client.ledger(validated()).unwrap();
+    client.ledger(validated()).unwrap();
+    assert_eq!(transport.requests().len(), 3);
+}
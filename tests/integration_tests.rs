@@ -2,12 +2,14 @@ use num_traits::cast::ToPrimitive;
 use serde_json::json;
 use serde_json::value::Value;
 use throttled_xrp_rpc::LedgerInfoParams;
-use throttled_xrp_rpc::{Account, AccountInfoParams, AccountTxParams, LedgerIndex, XRPClient};
+use throttled_xrp_rpc::{
+    Account, AccountInfoParams, AccountTxParams, LedgerIndex, RequestLedgerIndex, XRPClient,
+};
 
 #[macro_use]
 extern crate lazy_static;
 
-const FALL_BACK_URL: &'static str = "https://s1.ripple.com:51234/";
+const FALL_BACK_URL: &str = "https://s1.ripple.com:51234/";
 
 lazy_static! {
     static ref URL: String = std::env::var("XRP_NODE").unwrap_or_else(|_| {
@@ -24,10 +26,11 @@ fn account_info_tests() {
     let account_params = AccountInfoParams {
         account: &bitpay_account_id,
         strict: true,
-        ledger_index: LedgerIndex::StrValue {
+        ledger_index: RequestLedgerIndex::StrValue {
             ledger_index: "current".into(),
         },
         queue: true,
+        signer_lists: None,
     };
     let raw_response = client
         .post(&URL.clone())
@@ -40,7 +43,7 @@ fn account_info_tests() {
         .send()
         .unwrap()
         .json::<Value>();
-    let account_response = XRPClient::new(URL.clone().into(), None, None, 0, 0.0, 0)
+    let account_response = XRPClient::new(URL.clone(), None, None, 0, 0.0, 0)
         .account_info(account_params.clone());
     assert!(
         account_response.is_ok(),
@@ -66,10 +69,11 @@ fn account_info_tests() {
     let account_params = AccountInfoParams {
         account: &bitpay_account_id,
         strict: false,
-        ledger_index: LedgerIndex::Number {
+        ledger_index: RequestLedgerIndex::Number {
             ledger_index: new_index.into(),
         },
         queue: false,
+        signer_lists: None,
     };
     let raw_response = client
         .post(&URL.clone())
@@ -82,7 +86,7 @@ fn account_info_tests() {
         .send()
         .unwrap()
         .json::<Value>();
-    let account_response = XRPClient::new(URL.clone().into(), None, None, 0, 0.0, 0)
+    let account_response = XRPClient::new(URL.clone(), None, None, 0, 0.0, 0)
         .account_info(account_params.clone());
     assert!(
         account_response.is_ok(),
@@ -103,12 +107,13 @@ fn account_tx_test() {
         binary: Some(false),
         forward: Some(false),
         ledger_hash: None,
-        ledger_index: Some(LedgerIndex::StrValue {
+        ledger_index: Some(RequestLedgerIndex::StrValue {
             ledger_index: "current".into(),
         }),
         ledger_index_max: Some(-1),
         ledger_index_min: Some(-1),
         limit: Some(2),
+        marker: None,
     };
     let raw_response = client
         .post(&URL.clone())
@@ -121,7 +126,7 @@ fn account_tx_test() {
         .send()
         .unwrap()
         .json::<Value>();
-    let account_tx = XRPClient::new(URL.clone().into(), None, None, 0, 0.0, 0)
+    let account_tx = XRPClient::new(URL.clone(), None, None, 0, 0.0, 0)
         .account_tx(account_params.clone());
     assert!(
         account_tx.is_ok(),
@@ -136,7 +141,7 @@ fn account_tx_test() {
 fn account_ledger_test() {
     let ledger_params = LedgerInfoParams {
         ledger_hash: None,
-        ledger_index: Some(LedgerIndex::StrValue {
+        ledger_index: Some(RequestLedgerIndex::StrValue {
             ledger_index: "validated".into(),
         }),
         full: Some(false),
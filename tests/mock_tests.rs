@@ -0,0 +1,873 @@
+#![cfg(feature = "mock")]
+
+use bigdecimal::BigDecimal;
+use serde_json::json;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use throttled_xrp_rpc::capture::{DecodeError, IdStrategy};
+use throttled_xrp_rpc::metrics::AtomicMetrics;
+use throttled_xrp_rpc::retry::RetryPolicy;
+use throttled_xrp_rpc::timeout::{TimedOut, TimeoutPolicy};
+use throttled_xrp_rpc::{
+    Account, AccountInfoParams, AccountTxParams, BatchResponse, FailoverStrategy, LedgerCurrent,
+    MockTransport, RequestLedgerIndex, ServerState, SubmitParams, TransactionType, XRPClient,
+    XrpError,
+};
+#[cfg(feature = "async")]
+use throttled_xrp_rpc::{AsyncXRPClient, LedgerInfoParams};
+
+#[test]
+fn mock_account_info_test() {
+    let transport = MockTransport::new();
+    transport
+        .respond(
+            "account_info",
+            json!({
+                "account_data": {
+                    "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                    "Balance": "999999999960",
+                    "Flags": 8388608,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                    "PreviousTxnLgrSeq": 3,
+                    "Sequence": 6,
+                    "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+                },
+                "ledger_current_index": 4,
+                "status": "success",
+                "validated": false
+            }),
+        )
+        .respond(
+            "account_info",
+            json!({
+                "account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                "error": "actNotFound",
+                "error_code": 19,
+                "error_message": "Account not found.",
+                "status": "error",
+                "validated": false
+            }),
+        );
+    let client = XRPClient::with_transport(&transport);
+    let account: Account = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".parse().unwrap();
+    let params = AccountInfoParams {
+        account: &account,
+        strict: true,
+        ledger_index: RequestLedgerIndex::StrValue {
+            ledger_index: "current".into(),
+        },
+        queue: false,
+        signer_lists: None,
+    };
+
+    let info = client.account_info(params.clone()).unwrap();
+    assert_eq!(
+        info.account_data.unwrap().Account,
+        "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn"
+    );
+
+    let missing = client.account_info(params).unwrap_err();
+    assert_eq!(
+        missing.downcast_ref::<XrpError>().map(XrpError::code),
+        Some("actNotFound")
+    );
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0]["method"], "account_info");
+    assert_eq!(
+        requests[0]["params"][0]["account"],
+        "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn"
+    );
+}
+
+#[test]
+fn mock_batch_account_info_test() {
+    let transport = MockTransport::new();
+    transport
+        .respond(
+            "account_info",
+            json!({
+                "account_data": {
+                    "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                    "Balance": "999999999960",
+                    "Flags": 8388608,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                    "PreviousTxnLgrSeq": 3,
+                    "Sequence": 6,
+                    "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+                },
+                "ledger_current_index": 4,
+                "status": "success",
+                "validated": false
+            }),
+        )
+        .respond(
+            "account_info",
+            json!({
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "error": "actNotFound",
+                "error_code": 19,
+                "error_message": "Account not found.",
+                "status": "error",
+                "validated": false
+            }),
+        );
+    let found: Account = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".parse().unwrap();
+    let missing: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let params = |account| AccountInfoParams {
+        account,
+        strict: true,
+        ledger_index: RequestLedgerIndex::StrValue {
+            ledger_index: "current".into(),
+        },
+        queue: false,
+        signer_lists: None,
+    };
+
+    let client = XRPClient::with_transport(&transport);
+    let results = client
+        .batch()
+        .account_info(params(&found))
+        .account_info(params(&missing))
+        .send()
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    match &results[0] {
+        Ok(BatchResponse::AccountInfo(info)) => assert_eq!(
+            info.account_data.as_ref().unwrap().Account,
+            "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn"
+        ),
+        other => panic!("Expected account info, got {:?}", other),
+    }
+    assert_eq!(
+        results[1]
+            .as_ref()
+            .unwrap_err()
+            .downcast_ref::<XrpError>()
+            .map(XrpError::code),
+        Some("actNotFound")
+    );
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1]["params"][0]["account"], "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+    assert_eq!(transport.request_headers().len(), 1);
+}
+
+#[test]
+fn mock_failover_test() {
+    let fees = json!({
+        "current_ledger_size": "14",
+        "current_queue_size": "0",
+        "drops": {
+            "base_fee": "10",
+            "median_fee": "11000",
+            "minimum_fee": "10",
+            "open_ledger_fee": "10"
+        },
+        "expected_ledger_size": "24",
+        "ledger_current_index": 26575101,
+        "levels": {
+            "median_level": "281600",
+            "minimum_level": "256",
+            "open_ledger_level": "256",
+            "reference_level": "256"
+        },
+        "max_queue_size": "480",
+        "status": "success"
+    });
+    let down = MockTransport::new();
+    down.respond(
+        "fee",
+        json!({
+            "error": "noNetwork",
+            "error_code": 17,
+            "error_message": "Not synced to the network.",
+            "status": "error"
+        }),
+    );
+    let up = MockTransport::new();
+    up.respond("fee", fees);
+
+    let client = XRPClient::builder()
+        .urls(&[down.url(), up.url()])
+        .build_failover(FailoverStrategy::PrimaryWithFallback)
+        .unwrap();
+    for _ in 0..2 {
+        assert!(client.call(|client| client.fee()).is_ok());
+    }
+    assert_eq!(down.requests().len(), 2);
+    assert_eq!(up.requests().len(), 2);
+
+    // `unknownCmd` is not the node's fault, so the fallback is never asked
+    let error = client.call(|client| client.server_info()).unwrap_err();
+    assert_eq!(error.downcast_ref::<XrpError>().map(XrpError::code), Some("unknownCmd"));
+    assert_eq!(up.requests().len(), 2);
+}
+
+#[test]
+fn mock_health_syncing_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "server_state",
+        json!({
+            "state": {
+                "build_version": "1.5.0",
+                "complete_ledgers": "empty",
+                "load_base": 256,
+                "load_factor": 256,
+                "peers": 3,
+                "server_state": "syncing",
+                "server_state_duration_us": "1520768",
+                "uptime": 12
+            },
+            "status": "success"
+        }),
+    );
+    let health = XRPClient::with_transport(&transport).health().unwrap();
+    assert!(!health.healthy);
+    assert_eq!(health.server_state, ServerState::Syncing);
+    assert_eq!(health.latest_validated_ledger, None);
+    assert!(health.reason.is_some());
+}
+
+#[test]
+fn mock_account_reserve_test() {
+    let transport = MockTransport::new();
+    let server_info = json!({
+        "info": {
+            "build_version": "1.5.0",
+            "complete_ledgers": "32570-56865245",
+            "load_factor": 1,
+            "server_state": "full",
+            "validated_ledger": {
+                "age": 2,
+                "base_fee_xrp": 0.00001,
+                "hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+                "reserve_base_xrp": 20,
+                "reserve_inc_xrp": 5,
+                "seq": 56865245
+            }
+        },
+        "status": "success"
+    });
+    transport
+        .respond("server_info", server_info.clone())
+        .respond("server_info", server_info);
+    let client = XRPClient::with_transport(&transport);
+    assert_eq!(client.account_reserve(0).unwrap(), BigDecimal::from(20));
+    assert_eq!(client.account_reserve(3).unwrap(), BigDecimal::from(35));
+}
+
+#[test]
+fn mock_decode_error_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "account_info",
+        json!({
+            "account_data": { "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn", "Sequence": "six" },
+            "status": "success"
+        }),
+    );
+    let client = XRPClient::with_transport(&transport);
+    let account: Account = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".parse().unwrap();
+    let params = AccountInfoParams {
+        account: &account,
+        strict: true,
+        ledger_index: RequestLedgerIndex::StrValue {
+            ledger_index: "validated".into(),
+        },
+        queue: false,
+        signer_lists: None,
+    };
+    let error = client.account_info(params).unwrap_err();
+    assert_eq!(
+        error
+            .downcast_ref::<DecodeError>()
+            .map(|error| error.method.as_str()),
+        Some("account_info")
+    );
+    assert!(error.to_string().contains(r#""Sequence":"six""#));
+}
+
+#[test]
+fn mock_request_id_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "server_state",
+        json!({ "state": { "server_state": "full" }, "status": "success" }),
+    );
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .capture_raw(true)
+        .build()
+        .unwrap();
+    let envelope = client
+        .request_with_id::<serde_json::Value, _>("server_state", &json!({}), json!("export-7"))
+        .unwrap();
+    assert_eq!(envelope.id, json!("export-7"));
+    assert_eq!(transport.requests()[0]["id"], json!("export-7"));
+    assert_eq!(envelope.raw.unwrap()["state"]["server_state"], "full");
+
+    let first = client
+        .request::<serde_json::Value, _>("server_state", &json!({}))
+        .unwrap();
+    let second = client
+        .request::<serde_json::Value, _>("server_state", &json!({}))
+        .unwrap();
+    assert_eq!((first.id, second.id), (json!(1), json!(2)));
+
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .id_strategy(IdStrategy::Uuid)
+        .build()
+        .unwrap();
+    let envelope = client
+        .request::<serde_json::Value, _>("server_state", &json!({}))
+        .unwrap();
+    assert_eq!(envelope.id.as_str().map(str::len), Some(36));
+    assert!(envelope.raw.is_none());
+}
+
+#[test]
+fn mock_gzip_response_test() {
+    let transport = MockTransport::new();
+    transport.gzip(true).respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let gzip = XRPClient::builder().url(transport.url()).build().unwrap();
+    let current = gzip.ledger_current().unwrap();
+    assert_eq!(current.ledger_current_index, BigDecimal::from(56865245));
+    assert_eq!(transport.gzipped(), 1);
+
+    let plain = XRPClient::builder()
+        .url(transport.url())
+        .gzip(false)
+        .build()
+        .unwrap();
+    plain.ledger_current().unwrap();
+    assert_eq!(transport.gzipped(), 1);
+}
+
+#[test]
+fn mock_request_headers_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .header("X-Api-Key", "cluster-key")
+        .header("Content-Type", "text/plain")
+        .bearer_token("secret")
+        .build()
+        .unwrap();
+    let current = client.ledger_current();
+    assert!(current.is_ok(), "Getting back an error {:#?}", current);
+    let headers = &transport.request_headers()[0];
+    let values = |name: &str| -> Vec<&str> {
+        headers
+            .iter()
+            .filter(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    };
+    assert_eq!(values("x-api-key"), vec!["cluster-key"]);
+    assert_eq!(values("authorization"), vec!["Bearer secret"]);
+    assert_eq!(values("content-type"), vec!["application/json"]);
+}
+
+#[test]
+fn mock_account_lines_all_test() {
+    let transport = MockTransport::new();
+    let line = |peer: &str| {
+        json!({
+            "account": peer,
+            "balance": "1",
+            "currency": "USD",
+            "limit": "10",
+            "limit_peer": "0",
+            "quality_in": 0,
+            "quality_out": 0
+        })
+    };
+    transport
+        .respond(
+            "account_lines",
+            json!({
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "ledger_index": 4,
+                "validated": true,
+                "lines": [line("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")],
+                "marker": "page-2",
+                "status": "success"
+            }),
+        )
+        .respond(
+            "account_lines",
+            json!({
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "ledger_index": 4,
+                "validated": true,
+                "lines": [line("rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq")],
+                "status": "success"
+            }),
+        );
+    let client = XRPClient::with_transport(&transport);
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let lines = client.account_lines_all(&account, None).unwrap();
+    let peers: Vec<&str> = lines.iter().map(|line| line.account.as_str()).collect();
+    assert_eq!(
+        peers,
+        vec![
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq"
+        ]
+    );
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1]["params"][0]["marker"], "page-2");
+    assert_eq!(requests[0]["params"][0]["ledger_index"], "validated");
+    assert_eq!(requests[1]["params"][0]["ledger_index"], 4);
+}
+
+#[test]
+fn mock_metrics_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let metrics = Arc::new(AtomicMetrics::new());
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .metrics(metrics.clone())
+        .build()
+        .unwrap();
+    let current = client.ledger_current().unwrap();
+    assert_eq!(current.ledger_current_index, BigDecimal::from(56865245));
+    client.ledger_current().unwrap();
+    assert!(client.fee().is_err());
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.requests, 3);
+    assert_eq!(snapshot.methods["ledger_current"], 2);
+    assert_eq!(snapshot.methods["fee"], 1);
+    assert_eq!(snapshot.errors.get("unknownCmd"), Some(&1));
+    assert_eq!(snapshot.latency_buckets.iter().sum::<u64>(), 3);
+}
+
+#[test]
+fn mock_api_version_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let versioned = XRPClient::builder()
+        .url(transport.url())
+        .api_version(2)
+        .build()
+        .unwrap();
+    versioned.ledger_current().unwrap();
+    let plain = XRPClient::builder().url(transport.url()).build().unwrap();
+    plain.ledger_current().unwrap();
+    let requests = transport.requests();
+    assert_eq!(requests[0]["params"][0]["api_version"], 2);
+    assert!(requests[1]["params"][0].get("api_version").is_none());
+}
+
+#[test]
+fn mock_ledgers_in_range_test() {
+    let transport = MockTransport::new();
+    let ledger = |index: u64| {
+        json!({
+            "ledger": {
+                "closed": true,
+                "ledger_index": index.to_string(),
+                "parent_hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+                "seqNum": index.to_string(),
+                "transactions": []
+            },
+            "ledger_index": index,
+            "status": "success",
+            "validated": true
+        })
+    };
+    transport
+        .respond("ledger", ledger(56865245))
+        .respond(
+            "ledger",
+            json!({
+                "error": "tooBusy",
+                "error_code": 9,
+                "error_message": "The server is too busy to help you now.",
+                "status": "error"
+            }),
+        )
+        .respond("ledger", ledger(56865247));
+    let client = XRPClient::with_transport(&transport);
+    let ledgers: Vec<_> = client.ledgers_in_range(56865245, 56865247).collect();
+    assert_eq!(ledgers.len(), 3);
+    assert_eq!(ledgers[0].as_ref().unwrap().ledger_index.as_u64(), Some(56865245));
+    assert_eq!(
+        ledgers[1].as_ref().unwrap_err().downcast_ref::<XrpError>().unwrap().code(),
+        "tooBusy"
+    );
+    assert_eq!(ledgers[2].as_ref().unwrap().ledger_index.as_u64(), Some(56865247));
+    let requests = transport.requests();
+    let indexes: Vec<_> = requests
+        .iter()
+        .map(|request| request["params"][0]["ledger_index"].clone())
+        .collect();
+    assert_eq!(indexes, vec![json!(56865245), json!(56865246), json!(56865247)]);
+    assert_eq!(requests[0]["params"][0]["transactions"], true);
+    assert_eq!(requests[0]["params"][0]["expand"], true);
+}
+
+#[test]
+fn mock_account_exists_test() {
+    let transport = MockTransport::new();
+    transport
+        .respond(
+            "account_info",
+            json!({
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "error": "actNotFound",
+                "error_code": 19,
+                "error_message": "Account not found.",
+                "status": "error",
+                "validated": true
+            }),
+        )
+        .respond(
+            "account_info",
+            json!({
+                "error": "tooBusy",
+                "error_code": 9,
+                "error_message": "The server is too busy to help you now.",
+                "status": "error"
+            }),
+        );
+    let client = XRPClient::with_transport(&transport);
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    assert!(!client.account_exists(&account).unwrap());
+    let busy = client.account_exists(&account).unwrap_err();
+    assert_eq!(
+        busy.downcast_ref::<XrpError>().map(XrpError::code),
+        Some("tooBusy")
+    );
+    assert_eq!(transport.requests()[0]["params"][0]["ledger_index"], "validated");
+}
+
+#[test]
+fn mock_account_tx_filtered_test() {
+    let transport = MockTransport::new();
+    let entry = |transaction_type: &str, sequence: u32| {
+        json!({
+            "meta": { "AffectedNodes": [], "TransactionIndex": 0, "TransactionResult": "tesSUCCESS" },
+            "tx": {
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Fee": "12",
+                "Sequence": sequence,
+                "TransactionType": transaction_type,
+                "ledger_index": 56865245
+            },
+            "validated": true
+        })
+    };
+    transport.respond(
+        "account_tx",
+        json!({
+            "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "ledger_index_max": 56865245,
+            "ledger_index_min": 32570,
+            "limit": 10,
+            "status": "success",
+            "transactions": [
+                entry("Payment", 1),
+                entry("OfferCreate", 2),
+                entry("TrustSet", 3),
+                entry("Payment", 4)
+            ],
+            "validated": true
+        }),
+    );
+    let client = XRPClient::with_transport(&transport);
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+    let params = AccountTxParams {
+        account: &account,
+        ledger_index_min: Some(-1),
+        ledger_index_max: Some(-1),
+        ledger_hash: None,
+        ledger_index: None,
+        binary: Some(false),
+        forward: Some(false),
+        limit: Some(10),
+        marker: None,
+    };
+    let payments: Vec<_> = client
+        .account_tx_filtered(params, &[TransactionType::Payment])
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(payments.len(), 2);
+    assert!(payments
+        .iter()
+        .all(|entry| entry.transaction_type() == Some(TransactionType::Payment)));
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn mock_connection_reuse_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .max_idle_per_host(1)
+        .pool_idle_timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+    for _ in 0..3 {
+        client.ledger_current().unwrap();
+        // gives the connection time to go back to the pool
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(transport.connections(), 1);
+
+    thread::sleep(Duration::from_millis(300));
+    client.ledger_current().unwrap();
+    assert_eq!(transport.connections(), 2);
+}
+
+#[test]
+fn mock_response_warnings_test() {
+    let transport = MockTransport::new();
+    transport.respond(
+        "ledger_current",
+        json!({
+            "ledger_current_index": 56865245,
+            "status": "success",
+            "warning": "load",
+            "warnings": [{ "id": 1001, "message": "This server is amendment blocked" }]
+        }),
+    );
+    let client = XRPClient::with_transport(&transport);
+    let envelope = client
+        .request::<LedgerCurrent, _>("ledger_current", &json!({}))
+        .unwrap();
+    assert!(envelope.is_load_warning());
+    assert_eq!(envelope.warnings.as_ref().unwrap()[0].id, 1001);
+    let current = envelope.into_result().unwrap();
+    assert_eq!(current.ledger_current_index, BigDecimal::from(56865245));
+    assert!(client.ledger_current().is_ok());
+}
+
+#[test]
+fn mock_retry_test() {
+    let busy = json!({
+        "error": "tooBusy",
+        "error_code": 9,
+        "error_message": "The server is too busy to help you now.",
+        "status": "error"
+    });
+    let transport = MockTransport::new();
+    transport
+        .respond("ledger_current", busy.clone())
+        .respond("ledger_current", busy.clone())
+        .respond(
+            "ledger_current",
+            json!({ "ledger_current_index": 56865245, "status": "success" }),
+        )
+        .respond("submit", busy);
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .retry(RetryPolicy::new(3, Duration::from_millis(1)))
+        .build()
+        .unwrap();
+    let current = client.ledger_current().unwrap();
+    assert_eq!(current.ledger_current_index, BigDecimal::from(56865245));
+    assert_eq!(transport.requests().len(), 3);
+
+    let submitted = client.submit(SubmitParams {
+        tx_blob: "1200002280000000".into(),
+        fail_hard: None,
+    });
+    assert_eq!(
+        submitted
+            .unwrap_err()
+            .downcast_ref::<XrpError>()
+            .map(XrpError::code),
+        Some("tooBusy")
+    );
+    assert_eq!(transport.requests().len(), 4);
+}
+
+#[test]
+fn mock_timeout_test() {
+    let transport = MockTransport::new();
+    transport
+        .respond(
+            "ledger_current",
+            json!({ "ledger_current_index": 56865245, "status": "success" }),
+        )
+        .respond("fee", json!({ "status": "success" }))
+        .delay("ledger_current", Duration::from_millis(300))
+        .delay("fee", Duration::from_millis(300));
+    let client = XRPClient::builder()
+        .url(transport.url())
+        .timeout(
+            TimeoutPolicy::new(Duration::from_millis(100))
+                .with_timeout("ledger_current", Duration::from_secs(5)),
+        )
+        .build()
+        .unwrap();
+    let current = client.ledger_current().unwrap();
+    assert_eq!(current.ledger_current_index, BigDecimal::from(56865245));
+
+    let error = client.fee().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<TimedOut>(),
+        Some(&TimedOut {
+            method: "fee".into(),
+            timeout: Duration::from_millis(100),
+        })
+    );
+}
+
+/// Polls `future` on the current thread, parking it until the future is woken.
+#[cfg(feature = "async")]
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct Unpark(thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn mock_async_client_test() {
+    let transport = MockTransport::new();
+    transport
+        .respond(
+            "account_info",
+            json!({
+                "account_data": {
+                    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                    "Balance": "999999999960",
+                    "Flags": 0,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                    "PreviousTxnLgrSeq": 3,
+                    "Sequence": 6,
+                    "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+                },
+                "ledger_current_index": 4,
+                "status": "success",
+                "validated": false
+            }),
+        )
+        .respond(
+            "account_tx",
+            json!({
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "ledger_index_max": 56865245,
+                "ledger_index_min": 32570,
+                "limit": 10,
+                "status": "success",
+                "transactions": [],
+                "validated": true
+            }),
+        )
+        .respond(
+            "ledger",
+            json!({
+                "ledger": {
+                    "closed": true,
+                    "ledger_index": "56865245",
+                    "parent_hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+                    "seqNum": "56865245"
+                },
+                "ledger_hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+                "ledger_index": 56865245,
+                "status": "success",
+                "validated": true
+            }),
+        );
+    let client = AsyncXRPClient::new(transport.url().into(), None, None, 0.0).unwrap();
+    let account: Account = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".parse().unwrap();
+
+    let info = block_on(client.account_info(AccountInfoParams {
+        account: &account,
+        strict: true,
+        ledger_index: RequestLedgerIndex::StrValue {
+            ledger_index: "current".into(),
+        },
+        queue: false,
+        signer_lists: None,
+    }))
+    .unwrap();
+    assert_eq!(info.account_data.unwrap().Sequence, 6);
+
+    let account_tx = block_on(client.account_tx(AccountTxParams {
+        account: &account,
+        ledger_index_min: Some(-1),
+        ledger_index_max: Some(-1),
+        ledger_hash: None,
+        ledger_index: None,
+        binary: Some(false),
+        forward: Some(false),
+        limit: Some(10),
+        marker: None,
+    }))
+    .unwrap();
+    assert!(account_tx.transactions.is_empty());
+
+    let ledger = block_on(client.ledger(LedgerInfoParams {
+        ledger_hash: None,
+        ledger_index: Some(RequestLedgerIndex::Number {
+            ledger_index: 56_865_245.into(),
+        }),
+        full: None,
+        accounts: None,
+        transactions: None,
+        expand: None,
+        owner_funds: None,
+        binary: None,
+        queue: None,
+    }))
+    .unwrap();
+    assert!(ledger.validated);
+
+    let methods: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| request["method"].clone())
+        .collect();
+    assert_eq!(methods, vec!["account_info", "account_tx", "ledger"]);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "mock")]
+
+use serde_json::json;
+use std::thread;
+use std::time::{Duration, Instant};
+use throttled_xrp_rpc::{LedgerInfoParams, MockTransport, XRPClient};
+
+#[test]
+fn rate_limit_test() {
+    let server = MockTransport::new();
+    server.respond("fee", json!({
+        "current_ledger_size": "14",
+        "current_queue_size": "0",
+        "drops": {
+            "base_fee": "10",
+            "median_fee": "11000",
+            "minimum_fee": "10",
+            "open_ledger_fee": "10"
+        },
+        "expected_ledger_size": "24",
+        "ledger_current_index": 26575101,
+        "levels": {
+            "median_level": "281600",
+            "minimum_level": "256",
+            "open_ledger_level": "256",
+            "reference_level": "256"
+        },
+        "max_queue_size": "480",
+        "status": "success"
+    }));
+    let client = XRPClient::builder()
+        .url(server.url())
+        .rate_limit(10.0, 1)
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    let callers: Vec<_> = (0..4)
+        .map(|_| {
+            let client = client.clone();
+            thread::spawn(move || {
+                for _ in 0..5 {
+                    let fee = client.fee();
+                    assert!(fee.is_ok(), "Getting back an error {:#?}", fee);
+                }
+            })
+        })
+        .collect();
+    for caller in callers {
+        caller.join().unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    assert_eq!(server.requests().len(), 20);
+    // 20 requests at 10 per second need at least 19 gaps of 100ms
+    assert!(
+        elapsed >= Duration::from_millis(1900),
+        "20 requests at 10/s took only {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn rate_limit_burst_test() {
+    let server = MockTransport::new();
+    server.respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let client = XRPClient::builder()
+        .url(server.url())
+        .rate_limit(10.0, 5)
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    for _ in 0..5 {
+        let current = client.ledger_current();
+        assert!(current.is_ok(), "Getting back an error {:#?}", current);
+    }
+    let burst = start.elapsed();
+    for _ in 0..5 {
+        let current = client.ledger_current();
+        assert!(current.is_ok(), "Getting back an error {:#?}", current);
+    }
+    let elapsed = start.elapsed();
+
+    // the first 5 take the bucket's tokens, the next 5 wait for a refill each
+    assert!(burst < Duration::from_millis(400), "The burst took {:?}", burst);
+    assert!(
+        elapsed >= Duration::from_millis(450),
+        "10 requests with a burst of 5 at 10/s took only {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn rate_limit_batch_test() {
+    let server = MockTransport::new();
+    server.respond(
+        "ledger_current",
+        json!({ "ledger_current_index": 56865245, "status": "success" }),
+    );
+    let client = XRPClient::builder()
+        .url(server.url())
+        .rate_limit(10.0, 1)
+        .build()
+        .unwrap();
+    let ledger = LedgerInfoParams {
+        ledger_hash: None,
+        ledger_index: None,
+        full: None,
+        accounts: None,
+        transactions: None,
+        expand: None,
+        owner_funds: None,
+        binary: None,
+        queue: None,
+    };
+
+    let start = Instant::now();
+    let mut batch = client.batch();
+    for _ in 0..5 {
+        batch = batch.ledger(ledger.clone());
+    }
+    // no `ledger` response is registered, the entries only have to go out
+    assert_eq!(batch.send().unwrap().len(), 5);
+    let current = client.ledger_current();
+    assert!(current.is_ok(), "Getting back an error {:#?}", current);
+    let elapsed = start.elapsed();
+
+    assert_eq!(server.requests().len(), 6);
+    assert_eq!(server.request_headers().len(), 2);
+    // the batch takes 5 tokens at 10 per second, the call after it a 6th
+    assert!(
+        elapsed >= Duration::from_millis(450),
+        "A batch of 5 and a call at 10/s took only {:?}",
+        elapsed
+    );
+}
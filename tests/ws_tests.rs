@@ -0,0 +1,163 @@
+#![cfg(feature = "websocket")]
+
+use serde_json::json;
+use serde_json::value::Value;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use throttled_xrp_rpc::ws::{connect_path_find, subscribe_ledgers, PathFindParams};
+use throttled_xrp_rpc::{Account, Balance, Drops};
+use tungstenite::Message;
+
+fn ledger_closed(ledger_index: u64) -> Message {
+    Message::Text(
+        json!({
+            "type": "ledgerClosed",
+            "fee_base": 10,
+            "fee_ref": 10,
+            "ledger_hash": "687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464",
+            "ledger_index": ledger_index,
+            "ledger_time": 455751310,
+            "reserve_base": 20000000,
+            "reserve_inc": 5000000,
+            "txn_count": 7,
+            "validated_ledgers": "32570-7125358"
+        })
+        .to_string(),
+    )
+}
+
+/// Each connection gets the subscribe reply and two ledgers, then is dropped without a close frame.
+#[test]
+fn subscribe_ledgers_reconnect_test() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}/", listener.local_addr().unwrap());
+    let subscribes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = subscribes.clone();
+    thread::spawn(move || {
+        let mut next_ledger = 1;
+        for stream in listener.incoming() {
+            let mut socket = tungstenite::accept(stream.unwrap()).unwrap();
+            let subscribe: Value = match socket.read_message().unwrap() {
+                Message::Text(text) => serde_json::from_str(&text).unwrap(),
+                other => panic!("Expected a subscribe command, got {:?}", other),
+            };
+            recorded.lock().unwrap().push(subscribe.clone());
+            socket
+                .write_message(Message::Text(
+                    json!({
+                        "id": subscribe["id"],
+                        "result": { "ledger_index": next_ledger },
+                        "status": "success",
+                        "type": "response"
+                    })
+                    .to_string(),
+                ))
+                .unwrap();
+            for _ in 0..2 {
+                socket.write_message(ledger_closed(next_ledger)).unwrap();
+                next_ledger += 1;
+            }
+        }
+    });
+
+    let ledgers: Vec<u64> = subscribe_ledgers(&url)
+        .unwrap()
+        .reconnect_delay(Duration::from_millis(10))
+        .take(3)
+        .map(|event| event.unwrap().ledger_index.to_string().parse().unwrap())
+        .collect();
+    assert_eq!(ledgers, vec![1, 2, 3]);
+
+    let subscribes = subscribes.lock().unwrap();
+    assert_eq!(subscribes.len(), 2);
+    assert!(subscribes
+        .iter()
+        .all(|subscribe| subscribe["command"] == "subscribe" && subscribe["streams"] == json!(["ledger"])));
+}
+
+fn alternatives(source_amount: &str) -> Value {
+    json!([{
+        "paths_computed": [],
+        "source_amount": source_amount
+    }])
+}
+
+/// Answers `create` with the first update, pushes a second one, then records the `close`.
+#[test]
+fn path_find_two_updates_test() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}/", listener.local_addr().unwrap());
+    let commands = Arc::new(Mutex::new(Vec::new()));
+    let recorded = commands.clone();
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut socket = tungstenite::accept(stream).unwrap();
+        let create: Value = match socket.read_message().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("Expected create, got {:?}", other),
+        };
+        recorded.lock().unwrap().push(create.clone());
+        socket
+            .write_message(Message::Text(
+                json!({
+                    "id": create["id"],
+                    "result": {
+                        "alternatives": alternatives("1200"),
+                        "destination_account": create["destination_account"],
+                        "full_reply": false,
+                        "source_account": create["source_account"]
+                    },
+                    "status": "success",
+                    "type": "response"
+                })
+                .to_string(),
+            ))
+            .unwrap();
+        socket
+            .write_message(Message::Text(
+                json!({
+                    "alternatives": alternatives("1100"),
+                    "destination_account": create["destination_account"],
+                    "full_reply": true,
+                    "id": create["id"],
+                    "source_account": create["source_account"],
+                    "type": "path_find"
+                })
+                .to_string(),
+            ))
+            .unwrap();
+        let close = match socket.read_message().unwrap() {
+            Message::Text(text) => serde_json::from_str::<Value>(&text).unwrap(),
+            other => panic!("Expected close, got {:?}", other),
+        };
+        recorded.lock().unwrap().push(close);
+    });
+
+    let source: Account = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".parse().unwrap();
+    let destination: Account = "rKT4JX4cCof6LcDYRz8o3rGRu7qxzZ2Zwj".parse().unwrap();
+    let params = PathFindParams {
+        source_account: &source,
+        destination_account: &destination,
+        destination_amount: Balance::XRP(Drops(1000)),
+        send_max: None,
+    };
+    let mut connection = connect_path_find(&url).unwrap();
+    let full_replies: Vec<bool> = connection
+        .path_find_create(&params)
+        .unwrap()
+        .take(2)
+        .map(|update| update.unwrap().full_reply)
+        .collect();
+    assert_eq!(full_replies, vec![false, true]);
+    assert!(connection.path_find_create(&params).is_err());
+    connection.path_find_close().unwrap();
+    server.join().unwrap();
+
+    let commands = commands.lock().unwrap();
+    assert_eq!(commands[0]["command"], "path_find");
+    assert_eq!(commands[0]["subcommand"], "create");
+    assert_eq!(commands[0]["destination_amount"], "1000");
+    assert_eq!(commands[1]["subcommand"], "close");
+}